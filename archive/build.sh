@@ -36,6 +36,7 @@ wasm-strip dist/longrange_bg.wasm || echo "wasm-strip not available, skipping"
 echo "Copying static files..."
 cp index.html dist/
 cp _headers dist/
+cp _redirects dist/
 [ -f wrangler.toml ] && cp wrangler.toml dist/ || echo "No wrangler.toml found"
 
 echo "Verifying WASM module..."
@@ -0,0 +1,535 @@
+use egui::{ComboBox, Key, KeyboardShortcut, Modifiers, RichText, ThemePreference, Ui};
+use serde::{Deserialize, Serialize};
+
+use crate::ballistics::Load;
+use crate::ballistics_page::{self, BallisticsApp, BallisticsSetup};
+use crate::history::History;
+use crate::i18n::{self, Language, tr};
+use crate::library::{Library, LibraryWindow};
+use crate::mpbr_page::{MpbrApp, MpbrSetup};
+use crate::theme::{Palette, theme_label};
+use crate::top::{TopApp, TopInputs};
+use crate::units::UnitSystem;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tool {
+    TopGun,
+    Ballistics,
+    Mpbr,
+}
+
+impl Tool {
+    const ALL: [Tool; 3] = [Tool::TopGun, Tool::Ballistics, Tool::Mpbr];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Tool::TopGun => tr("TOP Gun"),
+            Tool::Ballistics => tr("Ballistics"),
+            Tool::Mpbr => tr("MPBR"),
+        }
+    }
+
+    /// URL path for the tool, matching the routes of the Elm app.
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    pub fn path(&self) -> &'static str {
+        match self {
+            Tool::TopGun => "/topgun",
+            Tool::Ballistics => "/ballistics",
+            Tool::Mpbr => "/mpbr",
+        }
+    }
+
+    /// Unknown paths fall back to the TOP calculator.
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    pub fn from_path(path: &str) -> Tool {
+        match path.trim_end_matches('/') {
+            "/ballistics" => Tool::Ballistics,
+            "/mpbr" => Tool::Mpbr,
+            _ => Tool::TopGun,
+        }
+    }
+}
+
+/// Settings shared by every calculator page, persisted between sessions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub units: UnitSystem,
+    pub theme: ThemePreference,
+    pub palette: Palette,
+    pub language: Language,
+}
+
+impl Settings {
+    /// Short descriptions of what differs from `previous`, for the history list.
+    fn changes(&self, previous: &Settings) -> Vec<String> {
+        let mut changes = Vec::new();
+        if self.units != previous.units {
+            changes.push(format!("{} {}", tr("Units:"), self.units.label()));
+        }
+        if self.theme != previous.theme {
+            changes.push(format!("{} {}", tr("Theme:"), theme_label(self.theme)));
+        }
+        if self.palette != previous.palette {
+            changes.push(format!("{} {}", tr("Plot colours:"), self.palette.label()));
+        }
+        if self.language != previous.language {
+            changes.push(format!("{} {}", tr("Language:"), self.language.label()));
+        }
+        changes
+    }
+}
+
+const SETTINGS_KEY: &str = "settings";
+const LIBRARY_KEY: &str = "library";
+const LOADS_KEY: &str = "loads";
+const BALLISTICS_KEY: &str = "ballistics";
+const MPBR_KEY: &str = "mpbr";
+
+const UNDO: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+const REDO: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
+const REDO_ALT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Y);
+
+/// Everything covered by undo/redo.
+#[derive(Debug, Clone, PartialEq)]
+struct Snapshot {
+    top: TopInputs,
+    settings: Settings,
+    ballistics: BallisticsSetup,
+    loads: Vec<Load>,
+    mpbr: MpbrSetup,
+}
+
+impl Snapshot {
+    fn describe(&self, previous: &Snapshot) -> String {
+        let units = self.settings.units;
+        let mut changes = self.top.changes(&previous.top, units);
+        changes.extend(self.settings.changes(&previous.settings));
+        changes.extend(self.ballistics.changes(&previous.ballistics, units));
+        changes.extend(ballistics_page::load_changes(&self.loads, &previous.loads));
+        // Loads are picked by index, so compare the loads they point at
+        let load_name = |snapshot: &Snapshot, index: usize| {
+            snapshot.loads.get(index).map(|load| load.name.clone())
+        };
+        for (label, index, old) in [
+            (
+                tr("Primary load:"),
+                self.ballistics.primary_load,
+                previous.ballistics.primary_load,
+            ),
+            (tr("Load:"), self.mpbr.load, previous.mpbr.load),
+        ] {
+            if let Some(name) = load_name(self, index)
+                && Some(&name) != load_name(previous, old).as_ref()
+            {
+                changes.push(format!("{label} {name}"));
+            }
+        }
+        changes.extend(self.mpbr.changes(&previous.mpbr, units));
+        changes.join(", ")
+    }
+}
+
+/// Navigation shell hosting the individual calculators.
+pub struct LongRangeApp {
+    tool: Tool,
+    settings: Settings,
+    top: TopApp,
+    ballistics: BallisticsApp,
+    mpbr: MpbrApp,
+    history: History<Snapshot>,
+    library: Library,
+    library_window: LibraryWindow,
+}
+
+impl LongRangeApp {
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let tool = {
+            web::repaint_on_navigation(cc.egui_ctx.clone());
+            web::current_path()
+                .map(|path| Tool::from_path(&path))
+                .unwrap_or(Tool::TopGun)
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let tool = Tool::TopGun;
+
+        let settings: Settings = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, SETTINGS_KEY))
+            .unwrap_or_default();
+        let library = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, LIBRARY_KEY))
+            .unwrap_or_default();
+        let loads: Vec<Load> = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, LOADS_KEY))
+            .unwrap_or_else(ballistics_page::default_loads);
+        let ballistics_setup: BallisticsSetup = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, BALLISTICS_KEY))
+            .unwrap_or_default();
+        let mpbr_setup: MpbrSetup = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, MPBR_KEY))
+            .unwrap_or_default();
+        let history = History::new(Snapshot {
+            top: TopApp::default().inputs(),
+            settings: settings.clone(),
+            ballistics: ballistics_setup.clone(),
+            loads: loads.clone(),
+            mpbr: mpbr_setup.clone(),
+        });
+
+        Self {
+            tool,
+            settings,
+            top: TopApp::default(),
+            ballistics: BallisticsApp::new(ballistics_setup, loads),
+            mpbr: MpbrApp::new(mpbr_setup),
+            history,
+            library,
+            library_window: LibraryWindow::default(),
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            top: self.top.inputs(),
+            settings: self.settings.clone(),
+            ballistics: self.ballistics.setup().clone(),
+            loads: self.ballistics.loads().to_vec(),
+            mpbr: self.mpbr.setup().clone(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.top.set_inputs(snapshot.top);
+        self.settings = snapshot.settings;
+        self.ballistics.restore(snapshot.ballistics, snapshot.loads);
+        self.mpbr.set_setup(snapshot.mpbr);
+    }
+
+    fn undo(&mut self) {
+        if let Some(snapshot) = self.history.undo().cloned() {
+            self.restore(snapshot);
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(snapshot) = self.history.redo().cloned() {
+            self.restore(snapshot);
+        }
+    }
+
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        // Leave Ctrl+Z to a text field that is being edited
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+        // Redo first: the undo shortcut also matches with Shift held
+        if ctx.input_mut(|i| i.consume_shortcut(&REDO) || i.consume_shortcut(&REDO_ALT)) {
+            self.redo();
+        } else if ctx.input_mut(|i| i.consume_shortcut(&UNDO)) {
+            self.undo();
+        }
+    }
+
+    /// Records the current state once the user has let go of any control,
+    /// so a slider drag becomes a single entry.
+    fn record_history(&mut self, ctx: &egui::Context) {
+        if !ctx.input(|i| i.pointer.any_down()) {
+            self.history.record(self.snapshot());
+        }
+    }
+
+    fn render_history(&mut self, ui: &mut Ui) {
+        let ctx = ui.ctx().clone();
+        if ui
+            .add_enabled(self.history.can_undo(), egui::Button::new("⟲"))
+            .on_hover_text(format!("{} ({})", tr("Undo"), ctx.format_shortcut(&UNDO)))
+            .clicked()
+        {
+            self.undo();
+        }
+        if ui
+            .add_enabled(self.history.can_redo(), egui::Button::new("⟳"))
+            .on_hover_text(format!("{} ({})", tr("Redo"), ctx.format_shortcut(&REDO)))
+            .clicked()
+        {
+            self.redo();
+        }
+
+        ui.menu_button(tr("History"), |ui| {
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .show(ui, |ui| {
+                    let entries = self.history.entries();
+                    let mut jump_to = None;
+                    // Newest first
+                    for index in (0..entries.len()).rev() {
+                        let label = match index {
+                            0 if self.history.is_truncated() => tr("Oldest kept state").to_string(),
+                            0 => tr("Initial state").to_string(),
+                            _ => entries[index].describe(&entries[index - 1]),
+                        };
+                        if ui
+                            .selectable_label(index == self.history.current_index(), label)
+                            .clicked()
+                        {
+                            jump_to = Some(index);
+                        }
+                    }
+                    if let Some(snapshot) = jump_to.and_then(|i| self.history.jump_to(i)) {
+                        let snapshot = snapshot.clone();
+                        self.restore(snapshot);
+                    }
+                });
+        });
+    }
+
+    fn select_tool(&mut self, tool: Tool) {
+        if tool == self.tool {
+            return;
+        }
+        self.tool = tool;
+        #[cfg(target_arch = "wasm32")]
+        web::push_path(tool.path());
+    }
+
+    fn render_nav_bar(&mut self, ui: &mut Ui) {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(RichText::new("r/longrange").strong());
+            ui.separator();
+
+            for tool in Tool::ALL {
+                if ui
+                    .selectable_label(self.tool == tool, tool.label())
+                    .clicked()
+                {
+                    self.select_tool(tool);
+                }
+            }
+
+            ui.separator();
+            self.render_history(ui);
+            ui.toggle_value(&mut self.library_window.open, tr("📚 Setups"));
+
+            ui.separator();
+            ui.menu_button(tr("⚙ Settings"), |ui| {
+                self.render_settings(ui);
+            });
+        });
+    }
+
+    fn render_settings(&mut self, ui: &mut Ui) {
+        egui::Grid::new("settings_grid")
+            .num_columns(2)
+            .show(ui, |ui| {
+                let label = ui.label(tr("Units:"));
+                ComboBox::from_id_salt("unit_system")
+                    .selected_text(self.settings.units.label())
+                    .show_ui(ui, |ui| {
+                        for units in [UnitSystem::Imperial, UnitSystem::Metric] {
+                            ui.selectable_value(&mut self.settings.units, units, units.label());
+                        }
+                    })
+                    .response
+                    .labelled_by(label.id);
+                ui.end_row();
+
+                let label = ui.label(tr("Theme:"));
+                ComboBox::from_id_salt("theme")
+                    .selected_text(theme_label(self.settings.theme))
+                    .show_ui(ui, |ui| {
+                        for theme in [
+                            ThemePreference::System,
+                            ThemePreference::Light,
+                            ThemePreference::Dark,
+                        ] {
+                            ui.selectable_value(
+                                &mut self.settings.theme,
+                                theme,
+                                theme_label(theme),
+                            );
+                        }
+                    })
+                    .response
+                    .labelled_by(label.id);
+                ui.end_row();
+
+                let label = ui.label(tr("Plot colours:"));
+                ComboBox::from_id_salt("palette")
+                    .selected_text(self.settings.palette.label())
+                    .show_ui(ui, |ui| {
+                        for palette in Palette::ALL {
+                            ui.selectable_value(
+                                &mut self.settings.palette,
+                                palette,
+                                palette.label(),
+                            );
+                        }
+                    })
+                    .response
+                    .labelled_by(label.id);
+                ui.end_row();
+
+                let label = ui.label(tr("Language:"));
+                ComboBox::from_id_salt("language")
+                    .selected_text(self.settings.language.label())
+                    .show_ui(ui, |ui| {
+                        for language in Language::ALL {
+                            ui.selectable_value(
+                                &mut self.settings.language,
+                                language,
+                                language.label(),
+                            );
+                        }
+                    })
+                    .response
+                    .labelled_by(label.id);
+                i18n::set_language(self.settings.language);
+                ui.end_row();
+            });
+    }
+}
+
+impl eframe::App for LongRangeApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, SETTINGS_KEY, &self.settings);
+        eframe::set_value(storage, LIBRARY_KEY, &self.library);
+        eframe::set_value(storage, LOADS_KEY, &self.ballistics.loads());
+        eframe::set_value(storage, BALLISTICS_KEY, self.ballistics.setup());
+        eframe::set_value(storage, MPBR_KEY, self.mpbr.setup());
+    }
+
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.handle_shortcuts(ctx);
+        i18n::set_language(self.settings.language);
+        if ctx.options(|options| options.theme_preference) != self.settings.theme {
+            ctx.set_theme(self.settings.theme);
+        }
+
+        // Follow the browser's back and forward buttons
+        #[cfg(target_arch = "wasm32")]
+        if let Some(path) = web::current_path() {
+            self.tool = Tool::from_path(&path);
+        }
+
+        egui::TopBottomPanel::top("nav_panel").show(ctx, |ui| {
+            self.render_nav_bar(ui);
+        });
+
+        self.library_window
+            .ui(ctx, &mut self.library, &mut self.top, self.settings.units);
+
+        match self.tool {
+            Tool::TopGun => self.top.ui(ctx, &self.settings),
+            Tool::Ballistics => self.ballistics.ui(ctx, &self.settings),
+            Tool::Mpbr => self.mpbr.ui(
+                ctx,
+                &self.settings,
+                self.ballistics.setup(),
+                self.ballistics.loads(),
+            ),
+        }
+
+        self.record_history(ctx);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use eframe::wasm_bindgen::{JsCast as _, JsValue, closure::Closure};
+
+    pub fn current_path() -> Option<String> {
+        web_sys::window()?.location().pathname().ok()
+    }
+
+    pub fn push_path(path: &str) {
+        if let Some(history) = web_sys::window().and_then(|window| window.history().ok()) {
+            let _ = history.push_state_with_url(&JsValue::NULL, "", Some(path));
+        }
+    }
+
+    /// egui only repaints on input, so wake it up when the URL changes.
+    pub fn repaint_on_navigation(ctx: egui::Context) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let callback = Closure::<dyn Fn()>::new(move || ctx.request_repaint());
+        let _ =
+            window.add_event_listener_with_callback("popstate", callback.as_ref().unchecked_ref());
+        callback.forget();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_paths_round_trip() {
+        for tool in Tool::ALL {
+            assert_eq!(Tool::from_path(tool.path()), tool);
+        }
+    }
+
+    #[test]
+    fn test_legacy_and_unknown_paths() {
+        assert_eq!(Tool::from_path("/"), Tool::TopGun);
+        assert_eq!(Tool::from_path(""), Tool::TopGun);
+        assert_eq!(Tool::from_path("/top"), Tool::TopGun);
+        assert_eq!(Tool::from_path("/mpbr/"), Tool::Mpbr);
+        assert_eq!(Tool::from_path("/nope"), Tool::TopGun);
+    }
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            top: TopApp::default().inputs(),
+            settings: Settings::default(),
+            ballistics: BallisticsSetup::default(),
+            loads: ballistics_page::default_loads(),
+            mpbr: MpbrSetup::default(),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_description() {
+        let before = snapshot();
+        let mut after = before.clone();
+        after.top.rifle_weight = 14.0;
+        after.settings.units = UnitSystem::Metric;
+        after.settings.theme = ThemePreference::Dark;
+
+        assert_eq!(
+            after.describe(&before),
+            "Rifle weight: 6.35 kg, Units: Metric, Theme: Dark"
+        );
+    }
+
+    #[test]
+    fn test_ballistics_and_mpbr_description() {
+        let before = snapshot();
+        let mut after = before.clone();
+        after.ballistics.rifle.sight_height = 2.0;
+        after.ballistics.wind.clear();
+        after.loads.remove(0);
+        after.mpbr.vital_zone = 6.0;
+        assert_eq!(
+            after.describe(&before),
+            "Sight height: 2.0 in, Environment, Removed NAS3 175Gr LRX, \
+             Primary load: NAS3 150 TTSX, Load: NAS3 150 TTSX, Vital zone diameter: 6.0 in"
+        );
+
+        let mut edited = before.clone();
+        edited.loads[1].muzzle_velocity += 50.0;
+        assert_eq!(edited.describe(&before), "Edited NAS3 150 TTSX");
+
+        let mut added = before.clone();
+        added.loads.push(ballistics_page::default_loads().remove(0));
+        assert_eq!(added.describe(&before), "Added NAS3 175Gr LRX");
+    }
+}
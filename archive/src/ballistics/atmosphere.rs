@@ -0,0 +1,245 @@
+//! Air at the firing point, from the ICAO standard atmosphere.
+//!
+//! Conditions are given as temperature, pressure, altitude and relative
+//! humidity, or as a density altitude. Drag scales with the density of the
+//! air, including its water vapour, relative to the standard sea-level
+//! atmosphere that BCs are referenced to.
+
+use serde::{Deserialize, Serialize};
+
+/// Standard sea-level temperature, in °F.
+const STANDARD_TEMPERATURE: f64 = 59.0;
+/// Standard sea-level pressure, in inches of mercury.
+const STANDARD_PRESSURE: f64 = 29.92;
+const RANKINE_OFFSET: f64 = 459.67;
+/// ICAO temperature lapse rate, in °F per foot, up to the tropopause.
+const LAPSE_RATE: f64 = 0.003_566_16;
+/// Altitude of the tropopause, in feet. The standard atmosphere is only
+/// modelled below it.
+const TROPOPAUSE: f64 = 36_089.0;
+/// Exponent of the ICAO pressure–altitude relation, g·M / (R·L).
+const PRESSURE_EXPONENT: f64 = 5.255_88;
+/// Water vapour weighs this much less than the dry air it displaces, per
+/// unit of partial pressure: 1 − 18.015 / 28.964.
+const VAPOUR_LIGHTNESS: f64 = 0.378;
+const HECTOPASCALS_PER_INCH_OF_MERCURY: f64 = 33.8639;
+
+/// What the pressure reading is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PressureReference {
+    /// The absolute pressure where the shooter stands, as a handheld
+    /// weather meter reads it.
+    #[default]
+    Station,
+    /// The barometric pressure corrected to sea level, as weather reports
+    /// and airports give it. The altitude turns it back into station
+    /// pressure.
+    SeaLevel,
+}
+
+/// Air at the firing point.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Atmosphere {
+    /// Temperature, in °F.
+    pub temperature: f64,
+    /// Pressure, in inches of mercury.
+    pub pressure: f64,
+    #[serde(default)]
+    pub pressure_reference: PressureReference,
+    /// Altitude above sea level, in feet. Only used to correct sea-level
+    /// pressure.
+    #[serde(default)]
+    pub altitude: f64,
+    /// Relative humidity, in percent.
+    #[serde(default)]
+    pub humidity: f64,
+}
+
+impl Default for Atmosphere {
+    fn default() -> Self {
+        Self::icao(0.0)
+    }
+}
+
+impl Atmosphere {
+    /// The ICAO standard atmosphere at `altitude` feet: dry air, with the
+    /// temperature and station pressure falling with height.
+    pub fn icao(altitude: f64) -> Self {
+        let altitude = altitude.min(TROPOPAUSE);
+        Self {
+            temperature: STANDARD_TEMPERATURE - LAPSE_RATE * altitude,
+            pressure: STANDARD_PRESSURE * standard_pressure_ratio(altitude),
+            pressure_reference: PressureReference::Station,
+            altitude,
+            humidity: 0.0,
+        }
+    }
+
+    /// Dry air at `temperature` °F that is as dense as the standard
+    /// atmosphere at `density_altitude` feet, for shooters who read density
+    /// altitude off a weather meter instead of pressure.
+    pub fn from_density_altitude(density_altitude: f64, temperature: f64) -> Self {
+        let ratio = standard_pressure_ratio(density_altitude.min(TROPOPAUSE))
+            .powf((PRESSURE_EXPONENT - 1.0) / PRESSURE_EXPONENT);
+        Self {
+            temperature,
+            pressure: STANDARD_PRESSURE * ratio * rankine(temperature)
+                / rankine(STANDARD_TEMPERATURE),
+            pressure_reference: PressureReference::Station,
+            altitude: 0.0,
+            humidity: 0.0,
+        }
+    }
+
+    /// Absolute pressure at the firing point, in inches of mercury.
+    pub fn station_pressure(&self) -> f64 {
+        match self.pressure_reference {
+            PressureReference::Station => self.pressure,
+            PressureReference::SeaLevel => {
+                self.pressure * standard_pressure_ratio(self.altitude.min(TROPOPAUSE))
+            }
+        }
+    }
+
+    /// Partial pressure of the water vapour in the air, in inches of
+    /// mercury.
+    fn vapour_pressure(&self) -> f64 {
+        let humidity = self.humidity.clamp(0.0, 100.0) / 100.0;
+        humidity * saturation_vapour_pressure(self.temperature)
+    }
+
+    /// Air density relative to the standard atmosphere at sea level. Moist
+    /// air is lighter than dry air at the same pressure.
+    pub fn density_ratio(&self) -> f64 {
+        let effective_pressure =
+            self.station_pressure() - VAPOUR_LIGHTNESS * self.vapour_pressure();
+        (effective_pressure / STANDARD_PRESSURE)
+            * (rankine(STANDARD_TEMPERATURE) / rankine(self.temperature))
+    }
+
+    /// Altitude at which the standard atmosphere is as dense as this air,
+    /// in feet.
+    pub fn density_altitude(&self) -> f64 {
+        let ratio = self.density_ratio().powf(1.0 / (PRESSURE_EXPONENT - 1.0));
+        rankine(STANDARD_TEMPERATURE) * (1.0 - ratio) / LAPSE_RATE
+    }
+
+    /// Speed of sound, in ft/s. Water vapour raises it slightly, as if the
+    /// air were warmer.
+    pub fn speed_of_sound(&self) -> f64 {
+        let virtual_temperature = rankine(self.temperature)
+            / (1.0 - VAPOUR_LIGHTNESS * self.vapour_pressure() / self.station_pressure());
+        49.0223 * virtual_temperature.sqrt()
+    }
+}
+
+fn rankine(fahrenheit: f64) -> f64 {
+    fahrenheit + RANKINE_OFFSET
+}
+
+/// Station pressure over sea-level pressure in the standard atmosphere.
+fn standard_pressure_ratio(altitude: f64) -> f64 {
+    (1.0 - LAPSE_RATE * altitude / rankine(STANDARD_TEMPERATURE)).powf(PRESSURE_EXPONENT)
+}
+
+/// Saturation vapour pressure over water at `temperature` °F, in inches of
+/// mercury, by the Buck equation.
+fn saturation_vapour_pressure(temperature: f64) -> f64 {
+    let celsius = (temperature - 32.0) * 5.0 / 9.0;
+    let hectopascals = 6.1121 * ((18.678 - celsius / 234.5) * (celsius / (257.14 + celsius))).exp();
+    hectopascals / HECTOPASCALS_PER_INCH_OF_MERCURY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_atmosphere() {
+        let standard = Atmosphere::default();
+        assert!((standard.density_ratio() - 1.0).abs() < 1e-12);
+        assert!((standard.speed_of_sound() - 1116.4).abs() < 0.1);
+        assert!(standard.density_altitude().abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_icao_table() {
+        // ICAO standard atmosphere at 5000 and 10,000 ft
+        let mile_high = Atmosphere::icao(5000.0);
+        assert!((mile_high.temperature - 41.17).abs() < 0.01);
+        assert!((mile_high.pressure - 24.90).abs() < 0.01);
+        assert!((mile_high.density_ratio() - 0.8617).abs() < 0.0005);
+        let high = Atmosphere::icao(10_000.0);
+        assert!((high.pressure - 20.58).abs() < 0.01);
+        assert!((high.density_ratio() - 0.7385).abs() < 0.0005);
+        assert!((high.speed_of_sound() - 1077.4).abs() < 0.5);
+        assert!((high.density_altitude() - 10_000.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_sea_level_pressure_is_corrected_for_altitude() {
+        let reported = Atmosphere {
+            pressure: 29.92,
+            pressure_reference: PressureReference::SeaLevel,
+            altitude: 5000.0,
+            ..Atmosphere::icao(5000.0)
+        };
+        assert!((reported.station_pressure() - 24.90).abs() < 0.01);
+        assert!((reported.density_ratio() - Atmosphere::icao(5000.0).density_ratio()).abs() < 1e-9);
+
+        // Altitude doesn't change a station reading
+        let station = Atmosphere {
+            altitude: 5000.0,
+            ..Atmosphere::default()
+        };
+        assert_eq!(
+            station.density_ratio(),
+            Atmosphere::default().density_ratio()
+        );
+    }
+
+    #[test]
+    fn test_humid_air_is_lighter() {
+        // Saturation vapour pressure at 68 °F is 23.4 hPa
+        assert!((saturation_vapour_pressure(68.0) * 33.8639 - 23.39).abs() < 0.05);
+
+        let dry = Atmosphere {
+            temperature: 86.0,
+            ..Atmosphere::default()
+        };
+        let humid = Atmosphere {
+            humidity: 100.0,
+            ..dry
+        };
+        // About 1.7% lighter at 30 °C
+        let lighter = 1.0 - humid.density_ratio() / dry.density_ratio();
+        assert!((lighter - 0.016).abs() < 0.001, "{lighter}");
+        assert!(humid.speed_of_sound() > dry.speed_of_sound());
+        assert!(humid.density_altitude() > dry.density_altitude());
+    }
+
+    #[test]
+    fn test_density_altitude_round_trip() {
+        for density_altitude in [-2000.0, 0.0, 4500.0, 12_000.0] {
+            for temperature in [10.0, 59.0, 100.0] {
+                let air = Atmosphere::from_density_altitude(density_altitude, temperature);
+                assert!(
+                    (air.density_altitude() - density_altitude).abs() < 1e-6,
+                    "{air:?}"
+                );
+                assert_eq!(air.temperature, temperature);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hot_thin_air_is_less_dense() {
+        let hot = Atmosphere {
+            temperature: 95.0,
+            pressure: 25.0,
+            ..Atmosphere::default()
+        };
+        assert!(hot.density_ratio() < 0.8);
+        assert!(hot.speed_of_sound() > Atmosphere::default().speed_of_sound());
+    }
+}
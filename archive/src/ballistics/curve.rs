@@ -0,0 +1,215 @@
+//! Custom drag models: a bullet's own Cd-vs-Mach curve, imported from a
+//! CSV or JSON file. The formats are documented in the README.
+
+use serde::{Deserialize, Serialize};
+
+use super::drag::interpolate;
+use crate::i18n::{format_number, parse_number, tr, tr_args};
+
+const GRAINS_PER_POUND: f64 = 7000.0;
+/// Drag coefficients above this are rejected as implausible for a bullet.
+const MAX_DRAG_COEFFICIENT: f64 = 2.0;
+
+/// A bullet's drag coefficient against Mach number, as measured by Doppler
+/// radar or published by the maker.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DragCurve {
+    pub name: String,
+    /// (Mach, Cd) points, in ascending Mach order.
+    pub points: Vec<(f64, f64)>,
+}
+
+impl DragCurve {
+    /// Drag coefficient at `mach`, linearly interpolated and clamped to the
+    /// ends of the curve.
+    pub fn drag_coefficient(&self, mach: f64) -> f64 {
+        interpolate(&self.points, mach)
+    }
+
+    /// Returns the first problem that would stop the curve being used.
+    pub fn validate(&self) -> Result<(), String> {
+        validate_points(&self.points)
+    }
+}
+
+/// Sectional density of a `weight` grain bullet `diameter` inches across,
+/// in lb/in². Against its own curve a bullet has a form factor of 1, so this
+/// takes the place of the BC.
+pub fn sectional_density(weight: f64, diameter: f64) -> f64 {
+    weight / GRAINS_PER_POUND / (diameter * diameter)
+}
+
+fn validate_points(points: &[(f64, f64)]) -> Result<(), String> {
+    if points.len() < 2 {
+        return Err(tr("A drag curve needs at least two points.").to_string());
+    }
+    for (i, &(mach, cd)) in points.iter().enumerate() {
+        let row = (i + 1).to_string();
+        if !mach.is_finite() || mach < 0.0 {
+            return Err(tr_args("Point {0}: Mach must not be negative.", &[&row]));
+        }
+        if i > 0 && mach <= points[i - 1].0 {
+            return Err(tr_args(
+                "Point {0}: Mach numbers must increase from point to point.",
+                &[&row],
+            ));
+        }
+        if !cd.is_finite() || cd <= 0.0 || cd > MAX_DRAG_COEFFICIENT {
+            return Err(tr_args(
+                "Point {0}: a Cd of {1} is not between 0 and {2}.",
+                &[
+                    &row,
+                    &format_number(cd, 4),
+                    &format_number(MAX_DRAG_COEFFICIENT, 0),
+                ],
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// A drag curve file. CSV files only carry the points; JSON files may also
+/// name the bullet and give its diameter and weight.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CurveFile {
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Bullet diameter, in inches.
+    #[serde(default)]
+    pub diameter: Option<f64>,
+    /// Bullet weight, in grains.
+    #[serde(default)]
+    pub weight: Option<f64>,
+    pub points: Vec<(f64, f64)>,
+}
+
+impl CurveFile {
+    /// Reads a JSON or CSV drag curve and checks its points.
+    pub fn parse(text: &str) -> Result<CurveFile, String> {
+        let file = if text.trim_start().starts_with('{') {
+            serde_json::from_str(text).map_err(|err| {
+                tr_args(
+                    "This is not a valid drag curve file: {0}",
+                    &[&err.to_string()],
+                )
+            })?
+        } else {
+            CurveFile {
+                name: None,
+                diameter: None,
+                weight: None,
+                points: parse_csv(text)?,
+            }
+        };
+        validate_points(&file.points)?;
+        Ok(file)
+    }
+}
+
+/// Reads `Mach,Cd` rows. A header row, blank lines and lines starting with
+/// `#` are skipped. Files using `;` or tabs between columns may use decimal
+/// commas.
+fn parse_csv(text: &str) -> Result<Vec<(f64, f64)>, String> {
+    let mut points = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let separator = if line.contains(';') {
+            ';'
+        } else if line.contains('\t') {
+            '\t'
+        } else {
+            ','
+        };
+        let fields: Vec<&str> = line.split(separator).map(str::trim).collect();
+        let parse = |field: &str| match separator {
+            ',' => field.parse::<f64>().ok(),
+            _ => parse_number(field),
+        };
+        match fields[..] {
+            [mach, cd] => match (parse(mach), parse(cd)) {
+                (Some(mach), Some(cd)) => points.push((mach, cd)),
+                // The first row may name the columns
+                _ if points.is_empty() => {}
+                _ => return Err(line_error(index)),
+            },
+            _ => return Err(line_error(index)),
+        }
+    }
+    Ok(points)
+}
+
+fn line_error(index: usize) -> String {
+    tr_args(
+        "Line {0}: expected a Mach number and a Cd.",
+        &[&(index + 1).to_string()],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_with_header_and_comments() {
+        let file = CurveFile::parse("# 6.5mm 140gr\nMach,Cd\n0.5, 0.230\n\n1.0,0.380\n2.0,0.300\n")
+            .unwrap();
+        assert_eq!(file.points, vec![(0.5, 0.23), (1.0, 0.38), (2.0, 0.3)]);
+        assert_eq!(file.diameter, None);
+    }
+
+    #[test]
+    fn test_csv_with_semicolons_and_decimal_commas() {
+        let file = CurveFile::parse("Mach;Cd\n0,5;0,230\n1,0;0,380\n").unwrap();
+        assert_eq!(file.points, vec![(0.5, 0.23), (1.0, 0.38)]);
+    }
+
+    #[test]
+    fn test_json() {
+        let json = r#"{
+            "name": "140gr match",
+            "diameter": 0.264,
+            "weight": 140,
+            "points": [[0.5, 0.23], [1.0, 0.38], [2.0, 0.30]]
+        }"#;
+        let file = CurveFile::parse(json).unwrap();
+        assert_eq!(file.name.as_deref(), Some("140gr match"));
+        assert_eq!(file.diameter, Some(0.264));
+        assert_eq!(file.weight, Some(140.0));
+        assert_eq!(file.points.len(), 3);
+    }
+
+    #[test]
+    fn test_rejects_bad_curves() {
+        let error = |text| CurveFile::parse(text).unwrap_err();
+        assert_eq!(
+            error("0.5,0.2\n"),
+            "A drag curve needs at least two points."
+        );
+        assert_eq!(
+            error("0.5,0.2\n0.5,0.3\n"),
+            "Point 2: Mach numbers must increase from point to point."
+        );
+        assert_eq!(
+            error("0.5,0.2\n1.0,3.5\n"),
+            "Point 2: a Cd of 3.5000 is not between 0 and 2."
+        );
+        assert_eq!(
+            error("0.5,0.2\n1.0\n"),
+            "Line 2: expected a Mach number and a Cd."
+        );
+        assert_eq!(
+            error("0.5,0.2\nfast,0.3\n"),
+            "Line 2: expected a Mach number and a Cd."
+        );
+        assert!(error("{\"points\": 3}").starts_with("This is not a valid drag curve file"));
+    }
+
+    #[test]
+    fn test_sectional_density() {
+        // 168gr .308: 0.253 lb/in²
+        assert!((sectional_density(168.0, 0.308) - 0.253).abs() < 0.001);
+    }
+}
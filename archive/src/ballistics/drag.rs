@@ -0,0 +1,738 @@
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+/// Standard projectile a ballistic coefficient is referenced to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DragModel {
+    /// Flat base, 2-calibre blunt ogive; the traditional reference for most
+    /// published BCs.
+    #[default]
+    G1,
+    /// Aberdeen J projectile: conical nose and a long boat-tail.
+    G2,
+    /// Short 7.5° boat-tail, 6.19-calibre tangent ogive.
+    G5,
+    /// Flat base, 6-calibre secant ogive.
+    G6,
+    /// Long 7.5° boat-tail, 10-calibre tangent ogive; a close match for
+    /// modern match bullets.
+    G7,
+    /// Flat base, 10-calibre secant ogive.
+    G8,
+    /// Ingalls' tables, the 19th-century forerunner of G1.
+    GI,
+}
+
+impl DragModel {
+    pub const ALL: [DragModel; 7] = [
+        DragModel::G1,
+        DragModel::G2,
+        DragModel::G5,
+        DragModel::G6,
+        DragModel::G7,
+        DragModel::G8,
+        DragModel::GI,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DragModel::G1 => "G1",
+            DragModel::G2 => "G2",
+            DragModel::G5 => "G5",
+            DragModel::G6 => "G6",
+            DragModel::G7 => "G7",
+            DragModel::G8 => "G8",
+            DragModel::GI => "GI",
+        }
+    }
+
+    /// Shape of the standard projectile, to help pick the closest match.
+    pub fn description(&self) -> &'static str {
+        match self {
+            DragModel::G1 => tr("Flat base, blunt nose. Most published BCs use it."),
+            DragModel::G2 => tr("Conical nose, long boat-tail (Aberdeen J)."),
+            DragModel::G5 => tr("Short boat-tail, tangent ogive."),
+            DragModel::G6 => tr("Flat base, long secant ogive."),
+            DragModel::G7 => tr("Long boat-tail, long ogive. Suits modern match bullets."),
+            DragModel::G8 => tr("Flat base, very long secant ogive."),
+            DragModel::GI => tr("Ingalls' tables, for old published BCs."),
+        }
+    }
+
+    /// Drag coefficient of the standard projectile at `mach`, linearly
+    /// interpolated and clamped to the ends of the table.
+    pub fn drag_coefficient(&self, mach: f64) -> f64 {
+        interpolate(self.table(), mach)
+    }
+
+    /// The standard projectile's (Mach, Cd) points.
+    pub(super) fn table(&self) -> &'static [(f64, f64)] {
+        match self {
+            DragModel::G1 => &G1,
+            DragModel::G2 => &G2,
+            DragModel::G5 => &G5,
+            DragModel::G6 => &G6,
+            DragModel::G7 => &G7,
+            DragModel::G8 => &G8,
+            DragModel::GI => &GI,
+        }
+    }
+}
+
+pub(super) fn interpolate(table: &[(f64, f64)], mach: f64) -> f64 {
+    let (first, last) = (table[0], table[table.len() - 1]);
+    if mach <= first.0 {
+        return first.1;
+    }
+    if mach >= last.0 {
+        return last.1;
+    }
+    let upper = table.partition_point(|(m, _)| *m < mach);
+    let (m0, cd0) = table[upper - 1];
+    let (m1, cd1) = table[upper];
+    cd0 + (mach - m0) / (m1 - m0) * (cd1 - cd0)
+}
+
+/// Standard G1 drag table: (Mach, Cd).
+const G1: [(f64, f64); 79] = [
+    (0.0, 0.2629),
+    (0.05, 0.2558),
+    (0.1, 0.2487),
+    (0.15, 0.2413),
+    (0.2, 0.2344),
+    (0.25, 0.2278),
+    (0.3, 0.2214),
+    (0.35, 0.2155),
+    (0.4, 0.2104),
+    (0.45, 0.2061),
+    (0.5, 0.2032),
+    (0.55, 0.2020),
+    (0.6, 0.2034),
+    (0.7, 0.2165),
+    (0.725, 0.2230),
+    (0.75, 0.2313),
+    (0.775, 0.2417),
+    (0.8, 0.2546),
+    (0.825, 0.2706),
+    (0.85, 0.2901),
+    (0.875, 0.3136),
+    (0.9, 0.3415),
+    (0.925, 0.3734),
+    (0.95, 0.4084),
+    (0.975, 0.4448),
+    (1.0, 0.4805),
+    (1.025, 0.5136),
+    (1.05, 0.5427),
+    (1.075, 0.5677),
+    (1.1, 0.5883),
+    (1.125, 0.6053),
+    (1.15, 0.6191),
+    (1.2, 0.6393),
+    (1.25, 0.6518),
+    (1.3, 0.6589),
+    (1.35, 0.6621),
+    (1.4, 0.6625),
+    (1.45, 0.6607),
+    (1.5, 0.6573),
+    (1.55, 0.6528),
+    (1.6, 0.6474),
+    (1.65, 0.6413),
+    (1.7, 0.6347),
+    (1.75, 0.6280),
+    (1.8, 0.6210),
+    (1.85, 0.6141),
+    (1.9, 0.6072),
+    (1.95, 0.6003),
+    (2.0, 0.5934),
+    (2.05, 0.5867),
+    (2.1, 0.5804),
+    (2.15, 0.5743),
+    (2.2, 0.5685),
+    (2.25, 0.5630),
+    (2.3, 0.5577),
+    (2.35, 0.5527),
+    (2.4, 0.5481),
+    (2.45, 0.5438),
+    (2.5, 0.5397),
+    (2.6, 0.5325),
+    (2.7, 0.5264),
+    (2.8, 0.5211),
+    (2.9, 0.5168),
+    (3.0, 0.5133),
+    (3.1, 0.5105),
+    (3.2, 0.5084),
+    (3.3, 0.5067),
+    (3.4, 0.5054),
+    (3.5, 0.5040),
+    (3.6, 0.5030),
+    (3.7, 0.5022),
+    (3.8, 0.5016),
+    (3.9, 0.5010),
+    (4.0, 0.5006),
+    (4.2, 0.4998),
+    (4.4, 0.4995),
+    (4.6, 0.4992),
+    (4.8, 0.4990),
+    (5.0, 0.4988),
+];
+
+/// Standard G7 drag table: (Mach, Cd).
+#[allow(clippy::approx_constant)]
+const G7: [(f64, f64); 84] = [
+    (0.0, 0.1198),
+    (0.05, 0.1197),
+    (0.1, 0.1196),
+    (0.15, 0.1194),
+    (0.2, 0.1193),
+    (0.25, 0.1194),
+    (0.3, 0.1194),
+    (0.35, 0.1194),
+    (0.4, 0.1193),
+    (0.45, 0.1193),
+    (0.5, 0.1194),
+    (0.55, 0.1193),
+    (0.6, 0.1194),
+    (0.65, 0.1197),
+    (0.7, 0.1202),
+    (0.725, 0.1207),
+    (0.75, 0.1215),
+    (0.775, 0.1226),
+    (0.8, 0.1242),
+    (0.825, 0.1266),
+    (0.85, 0.1306),
+    (0.875, 0.1368),
+    (0.9, 0.1464),
+    (0.925, 0.1660),
+    (0.95, 0.2054),
+    (0.975, 0.2993),
+    (1.0, 0.3803),
+    (1.025, 0.4015),
+    (1.05, 0.4043),
+    (1.075, 0.4034),
+    (1.1, 0.4014),
+    (1.125, 0.3987),
+    (1.15, 0.3955),
+    (1.2, 0.3884),
+    (1.25, 0.3810),
+    (1.3, 0.3732),
+    (1.35, 0.3657),
+    (1.4, 0.3580),
+    (1.5, 0.3440),
+    (1.55, 0.3376),
+    (1.6, 0.3315),
+    (1.65, 0.3260),
+    (1.7, 0.3209),
+    (1.75, 0.3160),
+    (1.8, 0.3117),
+    (1.85, 0.3078),
+    (1.9, 0.3042),
+    (1.95, 0.3010),
+    (2.0, 0.2980),
+    (2.05, 0.2951),
+    (2.1, 0.2922),
+    (2.15, 0.2892),
+    (2.2, 0.2864),
+    (2.25, 0.2835),
+    (2.3, 0.2807),
+    (2.35, 0.2779),
+    (2.4, 0.2752),
+    (2.45, 0.2725),
+    (2.5, 0.2697),
+    (2.55, 0.2670),
+    (2.6, 0.2643),
+    (2.65, 0.2615),
+    (2.7, 0.2588),
+    (2.75, 0.2561),
+    (2.8, 0.2533),
+    (2.85, 0.2506),
+    (2.9, 0.2479),
+    (2.95, 0.2451),
+    (3.0, 0.2424),
+    (3.1, 0.2368),
+    (3.2, 0.2313),
+    (3.3, 0.2258),
+    (3.4, 0.2205),
+    (3.5, 0.2154),
+    (3.6, 0.2106),
+    (3.7, 0.2060),
+    (3.8, 0.2017),
+    (3.9, 0.1975),
+    (4.0, 0.1935),
+    (4.2, 0.1861),
+    (4.4, 0.1793),
+    (4.6, 0.1730),
+    (4.8, 0.1672),
+    (5.0, 0.1618),
+];
+
+/// Standard G2 drag table: (Mach, Cd).
+const G2: [(f64, f64); 85] = [
+    (0.0, 0.2303),
+    (0.05, 0.2298),
+    (0.1, 0.2287),
+    (0.15, 0.2271),
+    (0.2, 0.2251),
+    (0.25, 0.2227),
+    (0.3, 0.2196),
+    (0.35, 0.2156),
+    (0.4, 0.2107),
+    (0.45, 0.2048),
+    (0.5, 0.1980),
+    (0.55, 0.1905),
+    (0.6, 0.1828),
+    (0.65, 0.1758),
+    (0.7, 0.1702),
+    (0.75, 0.1669),
+    (0.775, 0.1664),
+    (0.8, 0.1667),
+    (0.825, 0.1682),
+    (0.85, 0.1711),
+    (0.875, 0.1761),
+    (0.9, 0.1831),
+    (0.925, 0.2004),
+    (0.95, 0.2589),
+    (0.975, 0.3492),
+    (1.0, 0.3983),
+    (1.025, 0.4075),
+    (1.05, 0.4103),
+    (1.075, 0.4114),
+    (1.1, 0.4106),
+    (1.125, 0.4089),
+    (1.15, 0.4068),
+    (1.175, 0.4046),
+    (1.2, 0.4021),
+    (1.25, 0.3966),
+    (1.3, 0.3904),
+    (1.35, 0.3835),
+    (1.4, 0.3760),
+    (1.45, 0.3685),
+    (1.5, 0.3614),
+    (1.55, 0.3546),
+    (1.6, 0.3482),
+    (1.65, 0.3418),
+    (1.7, 0.3358),
+    (1.75, 0.3300),
+    (1.8, 0.3245),
+    (1.85, 0.3194),
+    (1.9, 0.3145),
+    (1.95, 0.3097),
+    (2.0, 0.3050),
+    (2.05, 0.3005),
+    (2.1, 0.2962),
+    (2.15, 0.2921),
+    (2.2, 0.2881),
+    (2.25, 0.2843),
+    (2.3, 0.2806),
+    (2.35, 0.2771),
+    (2.4, 0.2737),
+    (2.45, 0.2704),
+    (2.5, 0.2673),
+    (2.55, 0.2643),
+    (2.6, 0.2614),
+    (2.65, 0.2587),
+    (2.7, 0.2560),
+    (2.75, 0.2535),
+    (2.8, 0.2511),
+    (2.85, 0.2487),
+    (2.9, 0.2465),
+    (2.95, 0.2444),
+    (3.0, 0.2423),
+    (3.1, 0.2385),
+    (3.2, 0.2350),
+    (3.3, 0.2318),
+    (3.4, 0.2289),
+    (3.5, 0.2262),
+    (3.6, 0.2238),
+    (3.7, 0.2215),
+    (3.8, 0.2194),
+    (3.9, 0.2175),
+    (4.0, 0.2157),
+    (4.2, 0.2125),
+    (4.4, 0.2098),
+    (4.6, 0.2075),
+    (4.8, 0.2056),
+    (5.0, 0.2041),
+];
+
+/// Standard G5 drag table: (Mach, Cd).
+#[allow(clippy::approx_constant)]
+const G5: [(f64, f64); 76] = [
+    (0.0, 0.1710),
+    (0.05, 0.1719),
+    (0.1, 0.1727),
+    (0.15, 0.1732),
+    (0.2, 0.1734),
+    (0.25, 0.1730),
+    (0.3, 0.1718),
+    (0.35, 0.1696),
+    (0.4, 0.1668),
+    (0.45, 0.1637),
+    (0.5, 0.1603),
+    (0.55, 0.1566),
+    (0.6, 0.1529),
+    (0.65, 0.1497),
+    (0.7, 0.1473),
+    (0.75, 0.1463),
+    (0.8, 0.1489),
+    (0.85, 0.1583),
+    (0.875, 0.1672),
+    (0.9, 0.1815),
+    (0.925, 0.2051),
+    (0.95, 0.2413),
+    (0.975, 0.2884),
+    (1.0, 0.3379),
+    (1.025, 0.3785),
+    (1.05, 0.4032),
+    (1.075, 0.4147),
+    (1.1, 0.4201),
+    (1.15, 0.4278),
+    (1.2, 0.4338),
+    (1.25, 0.4373),
+    (1.3, 0.4392),
+    (1.35, 0.4403),
+    (1.4, 0.4406),
+    (1.45, 0.4401),
+    (1.5, 0.4386),
+    (1.55, 0.4362),
+    (1.6, 0.4328),
+    (1.65, 0.4286),
+    (1.7, 0.4237),
+    (1.75, 0.4182),
+    (1.8, 0.4121),
+    (1.85, 0.4057),
+    (1.9, 0.3991),
+    (1.95, 0.3926),
+    (2.0, 0.3861),
+    (2.05, 0.3800),
+    (2.1, 0.3741),
+    (2.15, 0.3684),
+    (2.2, 0.3630),
+    (2.25, 0.3578),
+    (2.3, 0.3529),
+    (2.35, 0.3481),
+    (2.4, 0.3435),
+    (2.45, 0.3391),
+    (2.5, 0.3349),
+    (2.6, 0.3269),
+    (2.7, 0.3194),
+    (2.8, 0.3125),
+    (2.9, 0.3060),
+    (3.0, 0.2999),
+    (3.1, 0.2942),
+    (3.2, 0.2889),
+    (3.3, 0.2838),
+    (3.4, 0.2790),
+    (3.5, 0.2745),
+    (3.6, 0.2703),
+    (3.7, 0.2662),
+    (3.8, 0.2624),
+    (3.9, 0.2588),
+    (4.0, 0.2553),
+    (4.2, 0.2488),
+    (4.4, 0.2429),
+    (4.6, 0.2376),
+    (4.8, 0.2326),
+    (5.0, 0.2280),
+];
+
+/// Standard G6 drag table: (Mach, Cd).
+const G6: [(f64, f64); 79] = [
+    (0.0, 0.2617),
+    (0.05, 0.2553),
+    (0.1, 0.2491),
+    (0.15, 0.2432),
+    (0.2, 0.2376),
+    (0.25, 0.2324),
+    (0.3, 0.2278),
+    (0.35, 0.2238),
+    (0.4, 0.2205),
+    (0.45, 0.2177),
+    (0.5, 0.2155),
+    (0.55, 0.2138),
+    (0.6, 0.2126),
+    (0.65, 0.2121),
+    (0.7, 0.2122),
+    (0.75, 0.2132),
+    (0.8, 0.2154),
+    (0.85, 0.2194),
+    (0.875, 0.2229),
+    (0.9, 0.2297),
+    (0.925, 0.2449),
+    (0.95, 0.2732),
+    (0.975, 0.3141),
+    (1.0, 0.3555),
+    (1.025, 0.3831),
+    (1.05, 0.3949),
+    (1.075, 0.3980),
+    (1.1, 0.3973),
+    (1.125, 0.3953),
+    (1.15, 0.3929),
+    (1.175, 0.3903),
+    (1.2, 0.3876),
+    (1.225, 0.3846),
+    (1.25, 0.3815),
+    (1.3, 0.3751),
+    (1.35, 0.3682),
+    (1.4, 0.3611),
+    (1.45, 0.3538),
+    (1.5, 0.3463),
+    (1.55, 0.3388),
+    (1.6, 0.3315),
+    (1.65, 0.3245),
+    (1.7, 0.3176),
+    (1.75, 0.3111),
+    (1.8, 0.3046),
+    (1.85, 0.2984),
+    (1.9, 0.2924),
+    (1.95, 0.2865),
+    (2.0, 0.2810),
+    (2.05, 0.2758),
+    (2.1, 0.2709),
+    (2.15, 0.2663),
+    (2.2, 0.2619),
+    (2.25, 0.2576),
+    (2.3, 0.2535),
+    (2.35, 0.2496),
+    (2.4, 0.2458),
+    (2.45, 0.2421),
+    (2.5, 0.2386),
+    (2.6, 0.2318),
+    (2.7, 0.2255),
+    (2.8, 0.2198),
+    (2.9, 0.2145),
+    (3.0, 0.2096),
+    (3.1, 0.2051),
+    (3.2, 0.2011),
+    (3.3, 0.1975),
+    (3.4, 0.1942),
+    (3.5, 0.1913),
+    (3.6, 0.1887),
+    (3.7, 0.1863),
+    (3.8, 0.1841),
+    (3.9, 0.1822),
+    (4.0, 0.1804),
+    (4.2, 0.1773),
+    (4.4, 0.1747),
+    (4.6, 0.1726),
+    (4.8, 0.1708),
+    (5.0, 0.1693),
+];
+
+/// Standard G8 drag table: (Mach, Cd).
+const G8: [(f64, f64); 78] = [
+    (0.0, 0.2105),
+    (0.05, 0.2105),
+    (0.1, 0.2104),
+    (0.15, 0.2104),
+    (0.2, 0.2103),
+    (0.25, 0.2103),
+    (0.3, 0.2103),
+    (0.35, 0.2103),
+    (0.4, 0.2103),
+    (0.45, 0.2102),
+    (0.5, 0.2102),
+    (0.55, 0.2102),
+    (0.6, 0.2102),
+    (0.65, 0.2102),
+    (0.7, 0.2103),
+    (0.75, 0.2103),
+    (0.8, 0.2104),
+    (0.825, 0.2104),
+    (0.85, 0.2105),
+    (0.875, 0.2106),
+    (0.9, 0.2109),
+    (0.925, 0.2183),
+    (0.95, 0.2571),
+    (0.975, 0.3358),
+    (1.0, 0.4068),
+    (1.025, 0.4378),
+    (1.05, 0.4476),
+    (1.075, 0.4493),
+    (1.1, 0.4477),
+    (1.125, 0.4450),
+    (1.15, 0.4419),
+    (1.2, 0.4353),
+    (1.25, 0.4283),
+    (1.3, 0.4208),
+    (1.35, 0.4133),
+    (1.4, 0.4059),
+    (1.45, 0.3986),
+    (1.5, 0.3915),
+    (1.55, 0.3845),
+    (1.6, 0.3777),
+    (1.65, 0.3710),
+    (1.7, 0.3645),
+    (1.75, 0.3581),
+    (1.8, 0.3519),
+    (1.85, 0.3458),
+    (1.9, 0.3400),
+    (1.95, 0.3343),
+    (2.0, 0.3287),
+    (2.05, 0.3233),
+    (2.1, 0.3181),
+    (2.15, 0.3131),
+    (2.2, 0.3083),
+    (2.25, 0.3036),
+    (2.3, 0.2991),
+    (2.35, 0.2948),
+    (2.4, 0.2906),
+    (2.45, 0.2866),
+    (2.5, 0.2826),
+    (2.6, 0.2752),
+    (2.7, 0.2682),
+    (2.8, 0.2616),
+    (2.9, 0.2553),
+    (3.0, 0.2494),
+    (3.1, 0.2439),
+    (3.2, 0.2386),
+    (3.3, 0.2336),
+    (3.4, 0.2289),
+    (3.5, 0.2244),
+    (3.6, 0.2201),
+    (3.7, 0.2161),
+    (3.8, 0.2122),
+    (3.9, 0.2086),
+    (4.0, 0.2051),
+    (4.2, 0.1986),
+    (4.4, 0.1927),
+    (4.6, 0.1872),
+    (4.8, 0.1823),
+    (5.0, 0.1771),
+];
+
+/// Standard GI drag table: (Mach, Cd).
+const GI: [(f64, f64); 81] = [
+    (0.0, 0.2282),
+    (0.05, 0.2282),
+    (0.1, 0.2282),
+    (0.15, 0.2282),
+    (0.2, 0.2282),
+    (0.25, 0.2282),
+    (0.3, 0.2282),
+    (0.35, 0.2282),
+    (0.4, 0.2282),
+    (0.45, 0.2282),
+    (0.5, 0.2282),
+    (0.55, 0.2282),
+    (0.6, 0.2282),
+    (0.65, 0.2282),
+    (0.7, 0.2282),
+    (0.725, 0.2353),
+    (0.75, 0.2434),
+    (0.775, 0.2515),
+    (0.8, 0.2596),
+    (0.825, 0.2677),
+    (0.85, 0.2759),
+    (0.875, 0.2913),
+    (0.9, 0.3170),
+    (0.925, 0.3442),
+    (0.95, 0.3728),
+    (1.0, 0.4349),
+    (1.05, 0.5034),
+    (1.075, 0.5402),
+    (1.1, 0.5756),
+    (1.125, 0.5887),
+    (1.15, 0.6018),
+    (1.175, 0.6149),
+    (1.2, 0.6279),
+    (1.225, 0.6418),
+    (1.25, 0.6423),
+    (1.3, 0.6423),
+    (1.35, 0.6423),
+    (1.4, 0.6423),
+    (1.45, 0.6423),
+    (1.5, 0.6423),
+    (1.55, 0.6423),
+    (1.6, 0.6423),
+    (1.625, 0.6407),
+    (1.65, 0.6378),
+    (1.7, 0.6321),
+    (1.75, 0.6266),
+    (1.8, 0.6213),
+    (1.85, 0.6163),
+    (1.9, 0.6113),
+    (1.95, 0.6066),
+    (2.0, 0.6020),
+    (2.05, 0.5976),
+    (2.1, 0.5933),
+    (2.15, 0.5891),
+    (2.2, 0.5850),
+    (2.25, 0.5811),
+    (2.3, 0.5773),
+    (2.35, 0.5733),
+    (2.4, 0.5679),
+    (2.45, 0.5626),
+    (2.5, 0.5576),
+    (2.6, 0.5478),
+    (2.7, 0.5386),
+    (2.8, 0.5298),
+    (2.9, 0.5215),
+    (3.0, 0.5136),
+    (3.1, 0.5061),
+    (3.2, 0.4989),
+    (3.3, 0.4921),
+    (3.4, 0.4855),
+    (3.5, 0.4792),
+    (3.6, 0.4732),
+    (3.7, 0.4674),
+    (3.8, 0.4618),
+    (3.9, 0.4564),
+    (4.0, 0.4512),
+    (4.2, 0.4413),
+    (4.4, 0.4320),
+    (4.6, 0.4232),
+    (4.8, 0.4149),
+    (5.0, 0.4070),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_points_are_exact() {
+        assert_eq!(DragModel::G1.drag_coefficient(1.0), 0.4805);
+        assert_eq!(DragModel::G7.drag_coefficient(2.0), 0.2980);
+    }
+
+    #[test]
+    fn test_interpolation_between_points() {
+        // Halfway between Mach 0.9 (0.3415) and 0.925 (0.3734)
+        let cd = DragModel::G1.drag_coefficient(0.9125);
+        assert!((cd - 0.35745).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tables_are_well_formed() {
+        for model in DragModel::ALL {
+            let machs: Vec<f64> = (0..=100).map(|i| f64::from(i) * 0.05).collect();
+            let cd: Vec<f64> = machs
+                .iter()
+                .map(|&mach| model.drag_coefficient(mach))
+                .collect();
+            assert!(cd.iter().all(|&cd| cd > 0.1 && cd < 0.7), "{model:?}");
+            // Drag rises through the sound barrier and peaks just above it
+            let (peak, _) =
+                cd.iter().enumerate().fold(
+                    (0, 0.0),
+                    |best, (i, &cd)| if cd > best.1 { (i, cd) } else { best },
+                );
+            assert!(
+                (1.0..=1.7).contains(&machs[peak]),
+                "{model:?} peaks at {}",
+                machs[peak]
+            );
+            assert!(
+                model.drag_coefficient(0.5) < model.drag_coefficient(1.0),
+                "{model:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_clamped_outside_table() {
+        assert_eq!(DragModel::G7.drag_coefficient(-1.0), 0.1198);
+        assert_eq!(DragModel::G7.drag_coefficient(6.0), 0.1618);
+    }
+}
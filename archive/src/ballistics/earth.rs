@@ -0,0 +1,76 @@
+//! The Earth's rotation, which bends long shots: the horizontal Coriolis
+//! effect and the vertical Eötvös effect.
+
+use serde::{Deserialize, Serialize};
+
+/// Sidereal rotation rate of the Earth, in rad/s.
+const ANGULAR_VELOCITY: f64 = 7.292_115e-5;
+
+/// Where on the Earth and in which direction the shot is fired.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct EarthRotation {
+    /// Latitude of the firing point, in degrees; positive north.
+    pub latitude: f64,
+    /// Azimuth of fire, in degrees clockwise from true north.
+    pub azimuth: f64,
+}
+
+impl EarthRotation {
+    /// The Earth's angular velocity in the solver's frame, in rad/s: x
+    /// downrange, y up and z to the right.
+    pub(super) fn angular_velocity(&self) -> [f64; 3] {
+        let (latitude, azimuth) = (self.latitude.to_radians(), self.azimuth.to_radians());
+        // The rotation axis points north and, away from the equator, up or
+        // down. Downrange and right are the azimuth's and its right angle's
+        // share of north.
+        let north = ANGULAR_VELOCITY * latitude.cos();
+        [
+            north * azimuth.cos(),
+            ANGULAR_VELOCITY * latitude.sin(),
+            -north * azimuth.sin(),
+        ]
+    }
+}
+
+/// Coriolis acceleration, −2 Ω × v, in ft/s² for `velocity` in ft/s.
+pub(super) fn coriolis_acceleration(omega: &[f64; 3], velocity: &[f64; 3]) -> [f64; 3] {
+    let [ox, oy, oz] = *omega;
+    let [vx, vy, vz] = *velocity;
+    [
+        -2.0 * (oy * vz - oz * vy),
+        -2.0 * (oz * vx - ox * vz),
+        -2.0 * (ox * vy - oy * vx),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn acceleration(latitude: f64, azimuth: f64) -> [f64; 3] {
+        let omega = EarthRotation { latitude, azimuth }.angular_velocity();
+        coriolis_acceleration(&omega, &[3000.0, 0.0, 0.0])
+    }
+
+    #[test]
+    fn test_deflects_right_in_the_north() {
+        let north = acceleration(45.0, 0.0);
+        assert!(north[2] > 0.0);
+        // Same deflection whichever way you face
+        assert!((acceleration(45.0, 123.0)[2] - north[2]).abs() < 1e-12);
+        assert!(acceleration(-45.0, 0.0)[2] < 0.0);
+        assert!(acceleration(0.0, 0.0)[2].abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_eotvos_lifts_eastward_shots() {
+        let east = acceleration(30.0, 90.0)[1];
+        let west = acceleration(30.0, 270.0)[1];
+        assert!(east > 0.0);
+        assert!((east + west).abs() < 1e-12);
+        // 2 Ω v cos φ at the equator
+        let equator = acceleration(0.0, 90.0)[1];
+        assert!((equator - 2.0 * ANGULAR_VELOCITY * 3000.0).abs() < 1e-9);
+        assert!(acceleration(30.0, 0.0)[1].abs() < 1e-12);
+    }
+}
@@ -0,0 +1,222 @@
+//! Adaptive Dormand–Prince 5(4) integration, with cubic Hermite
+//! interpolation between accepted steps.
+
+/// Largest factor a step may grow or shrink by at once.
+const MAX_GROWTH: f64 = 5.0;
+const MIN_GROWTH: f64 = 0.2;
+/// Safety factor applied to the step size the error estimate asks for.
+const SAFETY: f64 = 0.9;
+
+// Dormand–Prince tableau. The last stage is evaluated at the end of the
+// step, so its derivative is reused as the first stage of the next one.
+const C: [f64; 6] = [1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A2: [f64; 1] = [1.0 / 5.0];
+const A3: [f64; 2] = [3.0 / 40.0, 9.0 / 40.0];
+const A4: [f64; 3] = [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0];
+const A5: [f64; 4] = [
+    19372.0 / 6561.0,
+    -25360.0 / 2187.0,
+    64448.0 / 6561.0,
+    -212.0 / 729.0,
+];
+const A6: [f64; 5] = [
+    9017.0 / 3168.0,
+    -355.0 / 33.0,
+    46732.0 / 5247.0,
+    49.0 / 176.0,
+    -5103.0 / 18656.0,
+];
+/// Fifth-order weights, which are also the last stage's coefficients.
+const B: [f64; 6] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+];
+/// Difference between the fifth- and fourth-order weights, for all seven
+/// stages.
+const E: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+/// A point on the solution with its derivative.
+#[derive(Debug, Clone, Copy)]
+pub struct Node<const N: usize> {
+    pub x: f64,
+    pub y: [f64; N],
+    pub dydx: [f64; N],
+}
+
+/// Integrates `dy/dx = derivative(x, y)` forwards in `x`, choosing each step
+/// so the estimated local error stays within `tolerance`, relative to the
+/// size of each component (or absolute, for components smaller than one).
+pub struct Integrator<const N: usize, F> {
+    derivative: F,
+    tolerance: f64,
+    step: f64,
+    previous: Node<N>,
+    current: Node<N>,
+}
+
+impl<const N: usize, F: Fn(f64, &[f64; N]) -> [f64; N]> Integrator<N, F> {
+    pub fn new(derivative: F, x: f64, y: [f64; N], step: f64, tolerance: f64) -> Self {
+        let start = Node {
+            x,
+            y,
+            dydx: derivative(x, &y),
+        };
+        Self {
+            derivative,
+            tolerance,
+            step,
+            previous: start,
+            current: start,
+        }
+    }
+
+    /// End of the last accepted step.
+    pub fn current(&self) -> &Node<N> {
+        &self.current
+    }
+
+    /// Takes one accepted step. Returns `false`, without moving, if the step
+    /// size collapses, as it does when the derivative stops being finite.
+    pub fn step(&mut self) -> bool {
+        let Node { x, y, dydx: k1 } = self.current;
+        loop {
+            let h = self.step;
+            if x + h == x {
+                return false;
+            }
+            let f = &self.derivative;
+            let k2 = f(x + C[0] * h, &combine(&y, h, &A2, &[&k1]));
+            let k3 = f(x + C[1] * h, &combine(&y, h, &A3, &[&k1, &k2]));
+            let k4 = f(x + C[2] * h, &combine(&y, h, &A4, &[&k1, &k2, &k3]));
+            let k5 = f(x + C[3] * h, &combine(&y, h, &A5, &[&k1, &k2, &k3, &k4]));
+            let k6 = f(
+                x + C[4] * h,
+                &combine(&y, h, &A6, &[&k1, &k2, &k3, &k4, &k5]),
+            );
+            let next = combine(&y, h, &B, &[&k1, &k2, &k3, &k4, &k5, &k6]);
+            let k7 = f(x + C[5] * h, &next);
+
+            let error = combine(&[0.0; N], h, &E, &[&k1, &k2, &k3, &k4, &k5, &k6, &k7]);
+            let ratios = (0..N).map(|i| {
+                let scale = self.tolerance * (1.0 + y[i].abs().max(next[i].abs()));
+                error[i].abs() / scale
+            });
+            // `f64::max` would skip NaN, so fold by hand
+            let ratio = ratios.fold(0.0, |max, ratio| {
+                if ratio > max || ratio.is_nan() {
+                    ratio
+                } else {
+                    max
+                }
+            });
+
+            if !ratio.is_finite() {
+                self.step *= MIN_GROWTH;
+                continue;
+            }
+            let growth = (SAFETY * ratio.powf(-0.2)).clamp(MIN_GROWTH, MAX_GROWTH);
+            self.step *= growth;
+            if ratio <= 1.0 {
+                self.previous = self.current;
+                self.current = Node {
+                    x: x + h,
+                    y: next,
+                    dydx: k7,
+                };
+                return true;
+            }
+        }
+    }
+
+    /// Solution at `x`, which must lie within the last accepted step.
+    pub fn interpolate(&self, x: f64) -> [f64; N] {
+        let (start, end) = (&self.previous, &self.current);
+        let h = end.x - start.x;
+        if h == 0.0 {
+            return end.y;
+        }
+        let s = (x - start.x) / h;
+        let (s2, s3) = (s * s, s * s * s);
+        let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+        let h10 = s3 - 2.0 * s2 + s;
+        let h01 = -2.0 * s3 + 3.0 * s2;
+        let h11 = s3 - s2;
+        std::array::from_fn(|i| {
+            h00 * start.y[i] + h10 * h * start.dydx[i] + h01 * end.y[i] + h11 * h * end.dydx[i]
+        })
+    }
+}
+
+/// `y + h · Σ weights[j] · stages[j]`.
+fn combine<const N: usize>(
+    y: &[f64; N],
+    h: f64,
+    weights: &[f64],
+    stages: &[&[f64; N]],
+) -> [f64; N] {
+    std::array::from_fn(|i| {
+        y[i] + h * weights
+            .iter()
+            .zip(stages)
+            .map(|(weight, stage)| weight * stage[i])
+            .sum::<f64>()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_decay() {
+        let mut integrator = Integrator::new(|_, y: &[f64; 1]| [-y[0]], 0.0, [1.0], 0.01, 1e-10);
+        let mut steps = 0;
+        while integrator.current().x < 5.0 {
+            assert!(integrator.step());
+            steps += 1;
+        }
+        let x = integrator.current().x;
+        assert!((integrator.current().y[0] - (-x).exp()).abs() < 1e-9);
+        // Steps grow well past the initial 0.01
+        assert!(steps < 100, "{steps} steps");
+    }
+
+    #[test]
+    fn test_interpolation_within_step() {
+        // y = sin x, carried as (sin x, cos x)
+        let mut integrator =
+            Integrator::new(|_, y: &[f64; 2]| [y[1], -y[0]], 0.0, [0.0, 1.0], 0.1, 1e-10);
+        integrator.step();
+        integrator.step();
+        let (start, end) = (integrator.previous.x, integrator.current().x);
+        for i in 0..=10 {
+            let x = start + (end - start) * f64::from(i) / 10.0;
+            assert!((integrator.interpolate(x)[0] - x.sin()).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_non_finite_derivative_stops() {
+        let mut integrator = Integrator::new(
+            |x, _: &[f64; 1]| [if x > 1.0 { f64::NAN } else { 1.0 }],
+            0.0,
+            [0.0],
+            0.5,
+            1e-8,
+        );
+        while integrator.step() {}
+        assert!(integrator.current().x <= 1.0);
+    }
+}
@@ -0,0 +1,1357 @@
+//! Point-mass exterior ballistics.
+//!
+//! The bullet is treated as a point acted on by gravity and by the drag of a
+//! standard projectile ([`DragModel`]) scaled by its ballistic coefficient and
+//! the air density. The solver works in feet and seconds; the public API uses
+//! the units shooters quote: yards for range, inches for drop, drift and sight
+//! height, fps, mph and grains.
+//!
+//! This is a port of `ballistics_solver.py`, and the tests check it against
+//! the script's tables. The solver uses the standard G1 and G7 tables and
+//! drag constant where the script's own differ. Where the script takes fixed
+//! 0.1 ms Euler steps, the solver integrates over range with an adaptive
+//! Dormand–Prince 5(4) method and interpolates to land exactly on each
+//! requested range. It is held to within 0.01" of drop and drift, 0.01 fps
+//! and 10 µs of a fine-step RK4 reference out to 1000 yd, in a few dozen
+//! steps instead of about 16,000.
+
+mod atmosphere;
+mod curve;
+mod drag;
+mod earth;
+mod integrator;
+mod mpbr;
+mod spin;
+mod turret;
+mod wind;
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+pub use atmosphere::{Atmosphere, PressureReference};
+use curve::sectional_density;
+pub use curve::{CurveFile, DragCurve};
+pub use drag::DragModel;
+pub use earth::EarthRotation;
+pub use mpbr::PointBlankRange;
+pub use spin::TwistDirection;
+pub use turret::{Adjustment, AngleUnit, Turret};
+use wind::WindProfile;
+pub use wind::{Wind, WindZone};
+
+/// Gravitational acceleration, in ft/s².
+const GRAVITY: f64 = 32.174;
+/// Turns `Cd · v² / BC` into a deceleration in ft/s² for a BC in lb/in²:
+/// ρ₀ · π / (8 · 144), with the standard air density ρ₀ = 0.0764742 lb/ft³.
+const DRAG_CONSTANT: f64 = 2.085_51e-4;
+/// Local error allowed per integration step, relative to the size of each
+/// state component. Chosen to meet the accuracy target in the module docs.
+const TOLERANCE: f64 = 1e-8;
+/// Length of the first integration step, in feet; later steps adapt.
+const INITIAL_STEP: f64 = 10.0;
+/// Bullets slower than this are no longer tracked, in ft/s.
+const MIN_VELOCITY: f64 = 100.0;
+/// Flights longer than this are no longer tracked, in seconds. A draggy
+/// bullet can fall faster than [`MIN_VELOCITY`] indefinitely.
+const MAX_TIME: f64 = 15.0;
+/// Steepest bore elevation the zero search tries, in radians: 45°, past which
+/// raising the bore only shortens the flight.
+const MAX_ZERO_ELEVATION: f64 = std::f64::consts::FRAC_PI_4;
+/// First elevation step of the zero search, in radians; about 34 MOA.
+const ZERO_SEARCH_STEP: f64 = 0.01;
+/// How close the zero puts the bullet to the zero offset, in inches: far
+/// finer than a sight adjusts, but coarser than the integrator's noise.
+const ZERO_TOLERANCE: f64 = 1e-3;
+const MAX_ZERO_ITERATIONS: usize = 100;
+
+const FEET_PER_YARD: f64 = 3.0;
+const INCHES_PER_FOOT: f64 = 12.0;
+const FPS_PER_MPH: f64 = 5280.0 / 3600.0;
+/// Radians per minute of angle.
+const RADIANS_PER_MOA: f64 = std::f64::consts::PI / (180.0 * 60.0);
+
+/// Kinetic energy in foot-pounds of a bullet of `weight` grains at
+/// `velocity` ft/s.
+pub fn kinetic_energy(weight: f64, velocity: f64) -> f64 {
+    weight * velocity * velocity / 450_436.0
+}
+
+/// A cartridge load, as in the Elm app's load list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Load {
+    pub name: String,
+    /// Bullet weight, in grains.
+    pub weight: f64,
+    /// Ballistic coefficient, in lb/in².
+    pub bc: f64,
+    pub drag_model: DragModel,
+    /// Muzzle velocity, in ft/s.
+    pub muzzle_velocity: f64,
+    /// The bullet's own drag curve. When set, it is used instead of `bc` and
+    /// `drag_model`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drag_curve: Option<DragCurve>,
+    /// BCs for faster velocity bands, as Sierra and others publish them.
+    /// `bc` applies below the slowest band.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bc_bands: Vec<BcBand>,
+    /// Bullet diameter, in inches; zero if unknown. Needed for spin drift,
+    /// and by a drag curve, whose Cd is referenced to its cross-section.
+    #[serde(default)]
+    pub diameter: f64,
+    /// Bullet length, in inches; zero if unknown. Needed for spin drift.
+    #[serde(default)]
+    pub length: f64,
+}
+
+/// A ballistic coefficient that applies from `velocity` up to the next
+/// faster band.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BcBand {
+    /// Slowest velocity the band applies at, in ft/s.
+    pub velocity: f64,
+    /// Ballistic coefficient, in lb/in².
+    pub bc: f64,
+}
+
+impl Load {
+    /// Ballistic coefficient at `velocity` ft/s: that of the fastest band
+    /// the bullet has slowed into, or `bc` below them all.
+    pub fn bc_at(&self, velocity: f64) -> f64 {
+        self.bc_bands
+            .iter()
+            .filter(|band| velocity >= band.velocity)
+            .max_by(|a, b| a.velocity.total_cmp(&b.velocity))
+            .map_or(self.bc, |band| band.bc)
+    }
+
+    /// Drag coefficient divided by ballistic coefficient at `mach` and an
+    /// airspeed of `velocity` ft/s, which scales the drag deceleration.
+    fn drag_factor(&self, mach: f64, velocity: f64) -> f64 {
+        match &self.drag_curve {
+            Some(curve) => {
+                curve.drag_coefficient(mach) / sectional_density(self.weight, self.diameter)
+            }
+            None => self.drag_model.drag_coefficient(mach) / self.bc_at(velocity),
+        }
+    }
+}
+
+/// How the scope sits on the rifle.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rifle {
+    /// Height of the scope axis above the bore, in inches.
+    pub sight_height: f64,
+    /// Range at which the line of sight crosses the trajectory, in yards.
+    pub zero_range: f64,
+    /// Barrel twist, in inches per turn; zero if unknown.
+    #[serde(default)]
+    pub twist: f64,
+    #[serde(default)]
+    pub twist_direction: TwistDirection,
+    /// Where the rifle is zeroed to hit at the zero range, in inches above
+    /// the line of sight; 1 for "an inch high at 100".
+    #[serde(default)]
+    pub zero_height: f64,
+    /// Where the rifle is zeroed to hit at the zero range, in inches right
+    /// of the line of sight.
+    #[serde(default)]
+    pub zero_windage: f64,
+}
+
+/// The bore's angles to the line of sight that zero a load.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Zero {
+    /// Angle above the line of sight, in radians.
+    pub elevation: f64,
+    /// Angle right of the line of sight, in radians.
+    pub windage: f64,
+}
+
+/// Where a trajectory crosses a height relative to the line of sight, in
+/// yards; `None` if it doesn't within the range searched.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Crossings {
+    /// Where the bullet rises through the height.
+    pub near: Option<f64>,
+    /// Where it falls back through it.
+    pub far: Option<f64>,
+}
+
+/// The bullet's state at one of the requested ranges.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TrajectoryPoint {
+    /// Range, in yards.
+    pub range: f64,
+    /// Height relative to the line of sight, in inches; negative below it.
+    pub drop: f64,
+    /// `drop` as the rifleman's rule estimates it for an inclined shot, in
+    /// inches; the same as `drop` on level ground.
+    pub riflemans_rule: f64,
+    /// Share of `drop` due to aerodynamic jump, in inches; positive when it
+    /// lifts the bullet.
+    pub aerodynamic_jump: f64,
+    /// Sideways deflection by the wind, in inches; positive to the right.
+    pub drift: f64,
+    /// Sideways deflection by the bullet's spin, in inches; positive to the
+    /// right. Zero if the bullet's or barrel's dimensions are unknown.
+    pub spin_drift: f64,
+    /// Sideways deflection by the Earth's rotation, in inches; positive to
+    /// the right. Zero unless the solver accounts for the rotation.
+    pub coriolis: f64,
+    /// Share of `drop` due to the Earth's rotation, in inches; positive
+    /// when it lifts the bullet.
+    pub eotvos: f64,
+    /// Velocity, in ft/s.
+    pub velocity: f64,
+    /// Kinetic energy, in ft·lbf.
+    pub energy: f64,
+    /// Time of flight, in seconds.
+    pub time_of_flight: f64,
+}
+
+impl TrajectoryPoint {
+    /// Total sideways deflection, from wind, spin and the Earth's rotation,
+    /// in inches; positive to the right.
+    pub fn windage(&self) -> f64 {
+        self.drift + self.spin_drift + self.coriolis
+    }
+}
+
+/// The bullet's state at a given range, in a frame with x downrange along
+/// the line of sight, y up and z to the right, in feet, ft/s and seconds.
+#[derive(Debug, Clone, Copy)]
+struct State {
+    height: f64,
+    drift: f64,
+    velocity: [f64; 3],
+    time: f64,
+}
+
+impl State {
+    fn speed(&self) -> f64 {
+        let [vx, vy, vz] = self.velocity;
+        (vx * vx + vy * vy + vz * vz).sqrt()
+    }
+
+    fn to_array(self) -> [f64; 6] {
+        let [vx, vy, vz] = self.velocity;
+        [self.height, self.drift, vx, vy, vz, self.time]
+    }
+
+    fn from_array([height, drift, vx, vy, vz, time]: [f64; 6]) -> Self {
+        Self {
+            height,
+            drift,
+            velocity: [vx, vy, vz],
+            time,
+        }
+    }
+}
+
+/// Solves trajectories for one rifle in one set of conditions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solver {
+    pub rifle: Rifle,
+    pub atmosphere: Atmosphere,
+    /// Wind over each band of the range. The air is still short of the
+    /// first zone.
+    pub wind: Vec<WindZone>,
+    /// Where and which way the shot is fired, to account for the Earth's
+    /// rotation; `None` to ignore it.
+    pub earth_rotation: Option<EarthRotation>,
+    /// Angle of the line of sight above the horizontal, in degrees; positive
+    /// uphill, negative downhill.
+    pub look_angle: f64,
+    /// Air the rifle was zeroed in, if not `atmosphere`.
+    pub zero_atmosphere: Option<Atmosphere>,
+}
+
+/// What a flight is integrated through: the solver's own conditions, or the
+/// ones the zero is found in.
+#[derive(Clone, Copy)]
+struct Conditions<'a> {
+    atmosphere: Atmosphere,
+    wind: &'a [WindZone],
+    earth_rotation: Option<EarthRotation>,
+    /// Angle of the line of sight above the horizontal, in radians.
+    look_angle: f64,
+}
+
+impl Solver {
+    /// Bore angles that put `load` on the zero offset at the zero range. The
+    /// zero is found in the zero atmosphere, in still air on level ground,
+    /// ignoring the Earth's rotation.
+    ///
+    /// The elevation is bracketed between a bore that shoots low and one that
+    /// shoots high, searching up to 45°, then narrowed by regula falsi. Fails
+    /// if the bullet can't reach the zero range at any elevation or the search
+    /// doesn't converge.
+    pub fn zero(&self, load: &Load) -> Result<Zero, String> {
+        let zero_range = self.rifle.zero_range * FEET_PER_YARD;
+        if !zero_range.is_finite() || zero_range <= 0.0 {
+            return Err(tr("The zero range must be greater than zero.").to_string());
+        }
+        let conditions = self.zero_conditions();
+        // Height above the zero offset at the zero range, in inches, or
+        // `None` if the bullet falls short of it
+        let miss = |elevation| {
+            let mut miss = None;
+            let bore = Zero {
+                elevation,
+                windage: 0.0,
+            };
+            self.fly(load, bore, conditions, [zero_range], |_, state| {
+                miss = Some(state.height * INCHES_PER_FOOT - self.rifle.zero_height);
+            });
+            miss
+        };
+        let shoots_high = |miss: Option<f64>| miss.is_some_and(|miss| miss >= 0.0);
+
+        let mut low = (0.0, miss(0.0));
+        while shoots_high(low.1) {
+            if low.0 <= -MAX_ZERO_ELEVATION {
+                return Err(tr("The zero offset is out of reach.").to_string());
+            }
+            low.0 = (2.0 * low.0 - ZERO_SEARCH_STEP).max(-MAX_ZERO_ELEVATION);
+            low.1 = miss(low.0);
+        }
+        let mut high = (ZERO_SEARCH_STEP, miss(ZERO_SEARCH_STEP));
+        while !shoots_high(high.1) {
+            if high.0 >= MAX_ZERO_ELEVATION {
+                return Err(tr("The bullet can't reach the zero range.").to_string());
+            }
+            low = high;
+            high.0 = (2.0 * high.0).min(MAX_ZERO_ELEVATION);
+            high.1 = miss(high.0);
+        }
+
+        // Illinois regula falsi: halve the kept end's miss when the same end
+        // moves twice running, so the bracket can't stall on one side. Short
+        // flights give no miss to interpolate, so those steps bisect.
+        let mut moved_high = None;
+        for _ in 0..MAX_ZERO_ITERATIONS {
+            let elevation = match (low.1, high.1) {
+                (Some(low_miss), Some(high_miss)) if high_miss > low_miss => {
+                    (low.0 * high_miss - high.0 * low_miss) / (high_miss - low_miss)
+                }
+                _ => (low.0 + high.0) / 2.0,
+            };
+            let height = miss(elevation);
+            if height.is_some_and(|miss| miss.abs() < ZERO_TOLERANCE) {
+                // In still air on level ground drag slows the sideways and
+                // forward speeds alike, so the bullet keeps the bore's heading
+                let windage = (self.rifle.zero_windage / INCHES_PER_FOOT / zero_range).atan();
+                return Ok(Zero { elevation, windage });
+            }
+            if shoots_high(height) {
+                high = (elevation, height);
+                if moved_high == Some(true) {
+                    low.1 = low.1.map(|miss| miss / 2.0);
+                }
+                moved_high = Some(true);
+            } else {
+                low = (elevation, height);
+                if moved_high == Some(false) {
+                    high.1 = high.1.map(|miss| miss / 2.0);
+                }
+                moved_high = Some(false);
+            }
+        }
+        Err(tr("The zero search did not converge.").to_string())
+    }
+
+    /// Trajectory of `load` fired from a bore at the `zero` angles, sampled at
+    /// `ranges` (yards along the line of sight, ascending). Ranges the bullet
+    /// does not reach are left out.
+    pub fn trajectory(&self, load: &Load, zero: Zero, ranges: &[f64]) -> Vec<TrajectoryPoint> {
+        let mut points = Vec::with_capacity(ranges.len());
+        let stability = self.stability_factor(load).unwrap_or(0.0);
+        let jump = self.aerodynamic_jump(load).tan();
+        let feet = || ranges.iter().map(|range| range * FEET_PER_YARD);
+        let conditions = self.conditions();
+        // The Earth's share is the difference from the same shot on a
+        // still Earth
+        let mut still = Vec::with_capacity(ranges.len());
+        if self.earth_rotation.is_some() {
+            let still_earth = Conditions {
+                earth_rotation: None,
+                ..conditions
+            };
+            self.fly(load, zero, still_earth, feet(), |_, state| {
+                still.push((state.height, state.drift));
+            });
+        }
+        let riflemans_rule = self.riflemans_rule(load, zero, ranges);
+        self.fly(load, zero, conditions, feet(), |range, state| {
+            let velocity = state.speed();
+            let (height, drift) = match still.get(points.len()) {
+                Some(&still) => still,
+                None => (state.height, state.drift),
+            };
+            let jump = jump * range * INCHES_PER_FOOT;
+            let drop = state.height * INCHES_PER_FOOT + jump;
+            points.push(TrajectoryPoint {
+                range: range / FEET_PER_YARD,
+                drop,
+                riflemans_rule: riflemans_rule.get(points.len()).copied().unwrap_or(drop),
+                aerodynamic_jump: jump,
+                drift: drift * INCHES_PER_FOOT,
+                coriolis: (state.drift - drift) * INCHES_PER_FOOT,
+                eotvos: (state.height - height) * INCHES_PER_FOOT,
+                spin_drift: if stability > 0.0 {
+                    spin::spin_drift(stability, state.time, self.rifle.twist_direction)
+                } else {
+                    0.0
+                },
+                velocity,
+                energy: kinetic_energy(load.weight, velocity),
+                time_of_flight: state.time,
+            });
+        });
+        points
+    }
+
+    /// Where `load`, fired from a bore at the `zero` angles, crosses `height`
+    /// inches above the line of sight, out to `max_range` yards. With a
+    /// height of zero these are the near and far zeros; for another load than
+    /// the rifle was zeroed for, its equivalent zeros.
+    ///
+    /// The trajectory is sampled every yard, as in the script, and the
+    /// crossing interpolated between samples.
+    pub fn crossings(&self, load: &Load, zero: Zero, height: f64, max_range: f64) -> Crossings {
+        let ranges: Vec<f64> = (0..=max_range.max(0.0) as usize)
+            .map(|range| range as f64)
+            .collect();
+        let points = self.trajectory(load, zero, &ranges);
+        let mut crossings = Crossings::default();
+        for pair in points.windows(2) {
+            let (before, after) = (pair[0].drop - height, pair[1].drop - height);
+            let crossing =
+                || pair[0].range + (pair[1].range - pair[0].range) * before / (before - after);
+            if before < 0.0 && after >= 0.0 && crossings.near.is_none() {
+                crossings.near = Some(crossing());
+            } else if before >= 0.0 && after < 0.0 {
+                crossings.far = Some(crossing());
+                break;
+            }
+        }
+        crossings
+    }
+
+    /// Drop at each of `ranges` by the rifleman's rule, in inches: the hold
+    /// for a level shot at the horizontal distance to the target, applied
+    /// along the inclined line of sight. Empty for a level shot, which needs
+    /// no approximation.
+    fn riflemans_rule(&self, load: &Load, zero: Zero, ranges: &[f64]) -> Vec<f64> {
+        if self.look_angle == 0.0 {
+            return Vec::new();
+        }
+        let level = Solver {
+            look_angle: 0.0,
+            ..self.clone()
+        };
+        let cos = self.look_angle.to_radians().cos();
+        let horizontal: Vec<f64> = ranges.iter().map(|range| range * cos).collect();
+        level
+            .trajectory(load, zero, &horizontal)
+            .iter()
+            .map(|point| {
+                // The hold is an angle, so the level drop grows with the
+                // longer slant range. At the muzzle there is nothing to hold.
+                if point.range > 0.0 {
+                    point.drop / cos
+                } else {
+                    point.drop
+                }
+            })
+            .collect()
+    }
+
+    /// Vertical angle by which the crosswind at the muzzle throws `load` as
+    /// it leaves the barrel, in radians; positive up. Zero if the bullet's
+    /// or barrel's dimensions are unknown.
+    pub fn aerodynamic_jump(&self, load: &Load) -> f64 {
+        let Some(stability) = self.stability_factor(load) else {
+            return 0.0;
+        };
+        let crosswind = WindProfile::new(&self.wind).at(0.0)[2] / FPS_PER_MPH;
+        spin::aerodynamic_jump(
+            stability,
+            load.length / load.diameter,
+            crosswind,
+            self.rifle.twist_direction,
+        )
+    }
+
+    /// Gyroscopic stability factor of `load` from this barrel in this air,
+    /// or `None` if the bullet's or barrel's dimensions are unknown.
+    pub fn stability_factor(&self, load: &Load) -> Option<f64> {
+        spin::stability_factor(
+            load.weight,
+            load.diameter,
+            load.length,
+            self.rifle.twist,
+            load.muzzle_velocity,
+            self.atmosphere.density_ratio(),
+        )
+    }
+
+    fn conditions(&self) -> Conditions<'_> {
+        Conditions {
+            atmosphere: self.atmosphere,
+            wind: &self.wind,
+            earth_rotation: self.earth_rotation,
+            look_angle: self.look_angle.to_radians(),
+        }
+    }
+
+    fn zero_conditions(&self) -> Conditions<'static> {
+        Conditions {
+            atmosphere: self.zero_atmosphere.unwrap_or(self.atmosphere),
+            wind: &[],
+            earth_rotation: None,
+            look_angle: 0.0,
+        }
+    }
+
+    /// Integrates the flight from the muzzle through `conditions`, calling
+    /// `visit` with the state at each of `ranges` (feet, ascending) until the
+    /// bullet slows below [`MIN_VELOCITY`] or the flight passes [`MAX_TIME`].
+    fn fly(
+        &self,
+        load: &Load,
+        zero: Zero,
+        conditions: Conditions<'_>,
+        ranges: impl IntoIterator<Item = f64>,
+        mut visit: impl FnMut(f64, &State),
+    ) {
+        let mut integrator = integrator::Integrator::new(
+            self.derivative(load, conditions),
+            0.0,
+            self.launch(load, zero).to_array(),
+            INITIAL_STEP,
+            TOLERANCE,
+        );
+        let mut ranges = ranges.into_iter().peekable();
+        while let Some(&range) = ranges.peek() {
+            if range <= integrator.current().x {
+                visit(range, &State::from_array(integrator.interpolate(range)));
+                ranges.next();
+                continue;
+            }
+            let state = State::from_array(integrator.current().y);
+            if state.speed() < MIN_VELOCITY || state.time >= MAX_TIME || !integrator.step() {
+                break;
+            }
+        }
+    }
+
+    /// State at the muzzle. The bore sits `sight_height` below the line of
+    /// sight.
+    fn launch(&self, load: &Load, zero: Zero) -> State {
+        let v0 = load.muzzle_velocity;
+        let level = v0 * zero.elevation.cos();
+        State {
+            height: -self.rifle.sight_height / INCHES_PER_FOOT,
+            drift: 0.0,
+            velocity: [
+                level * zero.windage.cos(),
+                v0 * zero.elevation.sin(),
+                level * zero.windage.sin(),
+            ],
+            time: 0.0,
+        }
+    }
+
+    /// Rate of change of the state per foot of range in `conditions`.
+    ///
+    /// The frame is tilted with the line of sight, so on an inclined shot
+    /// gravity pulls partly along it, and the wind and the Earth's rotation,
+    /// given level, are tilted to match.
+    fn derivative(
+        &self,
+        load: &Load,
+        conditions: Conditions<'_>,
+    ) -> impl Fn(f64, &[f64; 6]) -> [f64; 6] {
+        let density_ratio = conditions.atmosphere.density_ratio();
+        let speed_of_sound = conditions.atmosphere.speed_of_sound();
+        let wind = WindProfile::new(conditions.wind);
+        let (sin, cos) = conditions.look_angle.sin_cos();
+        let tilt = move |[x, y, z]: [f64; 3]| [x * cos + y * sin, y * cos - x * sin, z];
+        let gravity = [-GRAVITY * sin, -GRAVITY * cos];
+        let omega = tilt(
+            conditions
+                .earth_rotation
+                .map_or([0.0; 3], |earth| earth.angular_velocity()),
+        );
+        move |range, state| {
+            let [_, _, vx, vy, vz, _] = *state;
+            // Drag acts against the velocity relative to the air
+            let [wx, wy, wz] = tilt(wind.at(range));
+            let air = [vx - wx, vy - wy, vz - wz];
+            let airspeed = (air[0] * air[0] + air[1] * air[1] + air[2] * air[2]).sqrt();
+            let drag_factor = load.drag_factor(airspeed / speed_of_sound, airspeed);
+            let drag = density_ratio * DRAG_CONSTANT * drag_factor * airspeed;
+            let [cx, cy, cz] = earth::coriolis_acceleration(&omega, &[vx, vy, vz]);
+
+            // d/dx = (d/dt) / vx
+            let per_foot = 1.0 / vx;
+            [
+                vy * per_foot,
+                vz * per_foot,
+                (-drag * air[0] + gravity[0] + cx) * per_foot,
+                (-drag * air[1] + gravity[1] + cy) * per_foot,
+                (-drag * air[2] + cz) * per_foot,
+                per_foot,
+            ]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(bc: f64, drag_model: DragModel, muzzle_velocity: f64, weight: f64) -> Load {
+        Load {
+            name: String::new(),
+            weight,
+            bc,
+            drag_model,
+            muzzle_velocity,
+            drag_curve: None,
+            bc_bands: Vec::new(),
+            diameter: 0.0,
+            length: 0.0,
+        }
+    }
+
+    /// A bore raised `elevation` radians above the line of sight.
+    fn bore(elevation: f64) -> Zero {
+        Zero {
+            elevation,
+            windage: 0.0,
+        }
+    }
+
+    /// A full-value wind, in mph; positive from the left.
+    fn crosswind(speed: f64) -> Wind {
+        Wind {
+            speed: speed.abs(),
+            direction: if speed < 0.0 { 90.0 } else { 270.0 },
+            vertical: 0.0,
+        }
+    }
+
+    /// The configuration at the top of `ballistics_solver.py`.
+    fn script_solver() -> Solver {
+        Solver {
+            rifle: Rifle {
+                sight_height: 1.9,
+                zero_range: 100.0,
+                twist: 0.0,
+                twist_direction: TwistDirection::Right,
+                zero_height: 0.0,
+                zero_windage: 0.0,
+            },
+            atmosphere: Atmosphere {
+                temperature: 60.0,
+                pressure: 29.92,
+                ..Atmosphere::default()
+            },
+            wind: vec![WindZone::uniform(crosswind(10.0))],
+            earth_rotation: None,
+            look_angle: 0.0,
+            zero_atmosphere: None,
+        }
+    }
+
+    const RANGES: [f64; 11] = [
+        0.0, 100.0, 200.0, 300.0, 400.0, 500.0, 600.0, 700.0, 800.0, 900.0, 1000.0,
+    ];
+
+    /// Compares against a table printed by `ballistics_solver.py` with the
+    /// standard G1 and G7 tables and drag constant in place of its own:
+    /// (range yd, drop in, wind in, velocity fps, time s). The script records
+    /// at the first step past each 100 yd, so its exact ranges are requested.
+    /// The tolerances cover the script's own Euler error.
+    fn assert_matches_script(
+        solver: &Solver,
+        load: &Load,
+        zero: Zero,
+        expected: &[(f64, f64, f64, f64, f64)],
+    ) {
+        let ranges: Vec<f64> = expected.iter().map(|row| row.0).collect();
+        let points = solver.trajectory(load, zero, &ranges);
+        assert_eq!(points.len(), expected.len());
+        for (point, &(range, drop, drift, velocity, time)) in points.iter().zip(expected) {
+            assert!((point.range - range).abs() < 1e-9, "{point:?}");
+            assert!((point.drop - drop).abs() < 0.1, "{point:?} drop {drop}");
+            assert!((point.drift - drift).abs() < 0.1, "{point:?} drift {drift}");
+            assert!((point.velocity - velocity).abs() < 0.5, "{point:?}");
+            assert!((point.time_of_flight - time).abs() < 0.0005, "{point:?}");
+        }
+    }
+
+    #[test]
+    fn test_matches_standard_drag_primary_load() {
+        let solver = script_solver();
+        let primary = load(0.254, DragModel::G7, 2725.0, 175.0);
+        let zero = solver.zero(&primary).unwrap();
+
+        assert_matches_script(
+            &solver,
+            &primary,
+            zero,
+            &[
+                (0.0, -1.900, 0.000, 2725.0, 0.0000),
+                (100.070, 0.000, 0.674, 2545.1, 0.1140),
+                (200.068, -3.478, 2.788, 2372.1, 0.2361),
+                (300.028, -13.140, 6.493, 2206.1, 0.3672),
+                (400.043, -29.963, 11.965, 2047.1, 0.5084),
+                (500.017, -55.089, 19.399, 1894.6, 0.6607),
+                (600.032, -89.934, 29.042, 1747.7, 0.8256),
+                (700.041, -136.191, 41.186, 1605.9, 1.0047),
+                (800.029, -195.958, 56.185, 1469.3, 1.2000),
+                (900.035, -271.895, 74.472, 1338.4, 1.4140),
+                (1000.017, -367.292, 96.530, 1214.3, 1.6494),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_matches_standard_drag_secondary_load() {
+        // A G1 load fired with the primary load's zero, as the script does
+        let solver = script_solver();
+        let primary = load(0.254, DragModel::G7, 2725.0, 175.0);
+        let secondary = load(0.470, DragModel::G1, 2700.0, 168.0);
+        let zero = solver.zero(&primary).unwrap();
+
+        assert_matches_script(
+            &solver,
+            &secondary,
+            zero,
+            &[
+                (0.000, -1.900, 0.000, 2700.0, 0.0000),
+                (100.007, -0.055, 0.736, 2508.1, 0.1153),
+                (200.035, -3.748, 3.052, 2324.1, 0.2396),
+                (300.051, -13.893, 7.130, 2147.9, 0.3739),
+                (400.047, -31.589, 13.183, 1979.5, 0.5194),
+                (500.044, -58.178, 21.454, 1819.4, 0.6775),
+                (600.032, -95.290, 32.208, 1668.6, 0.8497),
+                (700.015, -144.915, 45.726, 1528.4, 1.0376),
+                (800.022, -209.469, 62.284, 1400.4, 1.2428),
+                (900.023, -291.784, 82.099, 1286.8, 1.4665),
+                (1000.023, -395.124, 105.277, 1189.7, 1.7093),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_matches_script() {
+        // The script's own G7 table and drag constant, reproduced as a
+        // custom curve: its table scaled by the ratio of its constant to the
+        // standard one, for a bullet whose sectional density equals the BC.
+        // This checks the port against output the script prints as shipped.
+        const SCRIPT_G7: [(f64, f64); 50] = [
+            (0.00, 0.1198),
+            (0.50, 0.1197),
+            (0.60, 0.1196),
+            (0.70, 0.1194),
+            (0.80, 0.1193),
+            (0.85, 0.1194),
+            (0.875, 0.1210),
+            (0.90, 0.1256),
+            (0.925, 0.1382),
+            (0.95, 0.1618),
+            (0.975, 0.1903),
+            (1.00, 0.2124),
+            (1.025, 0.2278),
+            (1.05, 0.2378),
+            (1.075, 0.2436),
+            (1.10, 0.2464),
+            (1.125, 0.2470),
+            (1.15, 0.2460),
+            (1.20, 0.2405),
+            (1.25, 0.2318),
+            (1.30, 0.2218),
+            (1.35, 0.2115),
+            (1.40, 0.2015),
+            (1.45, 0.1920),
+            (1.50, 0.1832),
+            (1.55, 0.1750),
+            (1.60, 0.1676),
+            (1.65, 0.1608),
+            (1.70, 0.1547),
+            (1.75, 0.1491),
+            (1.80, 0.1440),
+            (1.85, 0.1393),
+            (1.90, 0.1350),
+            (1.95, 0.1310),
+            (2.00, 0.1273),
+            (2.05, 0.1239),
+            (2.10, 0.1207),
+            (2.15, 0.1178),
+            (2.20, 0.1150),
+            (2.25, 0.1125),
+            (2.30, 0.1101),
+            (2.35, 0.1078),
+            (2.40, 0.1058),
+            (2.45, 0.1038),
+            (2.50, 0.1020),
+            (2.60, 0.0987),
+            (2.70, 0.0957),
+            (2.80, 0.0929),
+            (2.90, 0.0904),
+            (3.00, 0.0880),
+        ];
+        const SCRIPT_CONSTANT: f64 = 0.000_710_54;
+
+        let solver = script_solver();
+        let standard = load(0.254, DragModel::G7, 2725.0, 175.0);
+        let script = Load {
+            drag_curve: Some(DragCurve {
+                name: "Script G7".to_string(),
+                points: SCRIPT_G7
+                    .iter()
+                    .map(|&(mach, cd)| (mach, cd * SCRIPT_CONSTANT / DRAG_CONSTANT))
+                    .collect(),
+            }),
+            diameter: (standard.weight / 7000.0 / standard.bc).sqrt(),
+            ..standard
+        };
+        let zero = solver.zero(&script).unwrap();
+
+        // Euler error grows with the script's much higher drag, so drop
+        // and drift are held to 0.05% rather than a tenth of an inch
+        let expected = [
+            (0.000, -1.900, 0.000, 2725.0, 0.0000),
+            (100.007, 0.000, 0.898, 2487.7, 0.1152),
+            (200.053, -3.745, 3.829, 2250.3, 0.2420),
+            (300.010, -14.386, 9.260, 2010.2, 0.3829),
+            (400.026, -33.703, 17.900, 1763.2, 0.5421),
+            (500.010, -64.320, 30.893, 1503.5, 0.7260),
+            (600.006, -110.584, 50.220, 1235.8, 0.9459),
+            (700.031, -179.864, 77.866, 1040.1, 1.2131),
+            (800.005, -281.015, 112.087, 937.0, 1.5176),
+            (900.028, -421.967, 152.019, 848.2, 1.8546),
+            (1000.004, -611.406, 198.172, 768.0, 2.2269),
+        ];
+        let ranges: Vec<f64> = expected.iter().map(|row| row.0).collect();
+        let points = solver.trajectory(&script, zero, &ranges);
+        assert_eq!(points.len(), expected.len());
+        for (point, &(_, drop, drift, velocity, time)) in points.iter().zip(&expected) {
+            assert!(
+                (point.drop - drop).abs() < 0.1_f64.max(drop.abs() * 5e-4),
+                "{point:?}"
+            );
+            assert!(
+                (point.drift - drift).abs() < 0.1_f64.max(drift.abs() * 5e-4),
+                "{point:?}"
+            );
+            assert!((point.velocity - velocity).abs() < 0.5, "{point:?}");
+            assert!((point.time_of_flight - time).abs() < 0.0005, "{point:?}");
+        }
+    }
+
+    /// Classic RK4 with 0.1 ft steps, landing on each of `ranges`.
+    fn reference(solver: &Solver, load: &Load, zero: Zero, ranges: &[f64]) -> Vec<State> {
+        const STEP: f64 = 0.1;
+        let f = solver.derivative(load, solver.conditions());
+        let mut y = solver.launch(load, zero).to_array();
+        let mut x = 0.0;
+        let mut states = Vec::new();
+        for &range in ranges {
+            let target = range * FEET_PER_YARD;
+            while x < target {
+                let h = STEP.min(target - x);
+                let add = |k: &[f64; 6], scale: f64| -> [f64; 6] {
+                    std::array::from_fn(|i| y[i] + scale * h * k[i])
+                };
+                let k1 = f(x, &y);
+                let k2 = f(x + h / 2.0, &add(&k1, 0.5));
+                let k3 = f(x + h / 2.0, &add(&k2, 0.5));
+                let k4 = f(x + h, &add(&k3, 1.0));
+                y = std::array::from_fn(|i| {
+                    y[i] + h / 6.0 * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i])
+                });
+                x += h;
+            }
+            states.push(State::from_array(y));
+        }
+        states
+    }
+
+    #[test]
+    fn test_accuracy_against_fine_step_reference() {
+        let solver = script_solver();
+        let loads = [
+            load(0.254, DragModel::G7, 2725.0, 175.0),
+            load(0.35, DragModel::G1, 3125.0, 130.0),
+            // Goes transonic before 1000 yd
+            load(0.25, DragModel::G1, 2600.0, 110.0),
+        ];
+        for load in &loads {
+            let zero = solver.zero(load).unwrap();
+            let points = solver.trajectory(load, zero, &RANGES);
+            let expected = reference(&solver, load, zero, &RANGES);
+            assert_eq!(points.len(), expected.len());
+            for (point, state) in points.iter().zip(&expected) {
+                assert!(
+                    (point.drop - state.height * INCHES_PER_FOOT).abs() < 0.01,
+                    "{point:?} {state:?}"
+                );
+                assert!(
+                    (point.drift - state.drift * INCHES_PER_FOOT).abs() < 0.01,
+                    "{point:?} {state:?}"
+                );
+                assert!(
+                    (point.velocity - state.speed()).abs() < 0.01,
+                    "{point:?} {state:?}"
+                );
+                assert!(
+                    (point.time_of_flight - state.time).abs() < 1e-5,
+                    "{point:?} {state:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_published_drop_for_308_match_load() {
+        // 168gr .308 match bullet (G1 0.462) at 2650 fps, 1.5" sight height,
+        // 100 yd zero, standard atmosphere: published tables give about
+        // 40 MOA (-420") of drop and 1150 fps at 1000 yd.
+        let solver = Solver {
+            rifle: Rifle {
+                sight_height: 1.5,
+                zero_range: 100.0,
+                twist: 0.0,
+                twist_direction: TwistDirection::Right,
+                zero_height: 0.0,
+                zero_windage: 0.0,
+            },
+            atmosphere: Atmosphere::default(),
+            wind: Vec::new(),
+            earth_rotation: None,
+            look_angle: 0.0,
+            zero_atmosphere: None,
+        };
+        let load = load(0.462, DragModel::G1, 2650.0, 168.0);
+        let zero = solver.zero(&load).unwrap();
+        let point = solver.trajectory(&load, zero, &[1000.0])[0];
+        assert!((point.drop + 420.0).abs() < 15.0, "{point:?}");
+        assert!((point.velocity - 1150.0).abs() < 50.0, "{point:?}");
+    }
+
+    #[test]
+    fn test_custom_curve_matches_equivalent_bc() {
+        // A curve that is the G7 curve scaled by the bullet's form factor
+        // flies exactly like the G7 BC it implies.
+        let solver = script_solver();
+        let standard = load(0.254, DragModel::G7, 2725.0, 175.0);
+        let diameter = 0.308;
+        let form_factor = standard.weight / 7000.0 / (diameter * diameter) / standard.bc;
+        let custom = Load {
+            drag_curve: Some(DragCurve {
+                name: "175gr".to_string(),
+                points: DragModel::G7
+                    .table()
+                    .iter()
+                    .map(|&(mach, cd)| (mach, cd * form_factor))
+                    .collect(),
+            }),
+            diameter,
+            // Ignored in favour of the curve
+            bc: 1.0,
+            drag_model: DragModel::G1,
+            ..standard.clone()
+        };
+        let zero = solver.zero(&standard).unwrap();
+        let expected = solver.trajectory(&standard, zero, &RANGES);
+        let actual = solver.trajectory(&custom, zero, &RANGES);
+        for (expected, actual) in expected.iter().zip(&actual) {
+            // Rounding can tip the step size control either way, so the
+            // two agree to well within the solver's accuracy, not exactly
+            assert!((expected.drop - actual.drop).abs() < 1e-3, "{actual:?}");
+            assert!(
+                (expected.velocity - actual.velocity).abs() < 1e-2,
+                "{actual:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bc_bands() {
+        let mut load = load(0.475, DragModel::G1, 2900.0, 168.0);
+        let flat = load.clone();
+        load.bc_bands = vec![
+            BcBand {
+                velocity: 2000.0,
+                bc: 0.496,
+            },
+            BcBand {
+                velocity: 2800.0,
+                bc: 0.505,
+            },
+            BcBand {
+                velocity: 1600.0,
+                bc: 0.485,
+            },
+        ];
+        assert_eq!(load.bc_at(2900.0), 0.505);
+        assert_eq!(load.bc_at(2800.0), 0.505);
+        assert_eq!(load.bc_at(2500.0), 0.496);
+        assert_eq!(load.bc_at(1700.0), 0.485);
+        assert_eq!(load.bc_at(1200.0), 0.475);
+
+        // Higher BCs while fast means less drop than the lowest BC alone,
+        // but more than the muzzle BC alone
+        let solver = script_solver();
+        let drop = |load: &Load| solver.trajectory(load, bore(0.0), &[1000.0])[0].drop;
+        let fast = Load {
+            bc: 0.505,
+            ..flat.clone()
+        };
+        assert!(drop(&flat) < drop(&load));
+        assert!(drop(&load) < drop(&fast));
+
+        // A band the bullet never reaches changes nothing
+        load.bc_bands = vec![BcBand {
+            velocity: 3500.0,
+            bc: 0.9,
+        }];
+        assert_eq!(drop(&load), drop(&flat));
+    }
+
+    #[test]
+    fn test_crosswind_direction() {
+        let mut solver = script_solver();
+        let load = load(0.5, DragModel::G1, 2800.0, 150.0);
+        let drift = |solver: &Solver| solver.trajectory(&load, bore(0.001), &[500.0])[0].drift;
+
+        assert!(drift(&solver) > 0.0);
+        solver.wind = vec![WindZone::uniform(crosswind(-10.0))];
+        assert!(drift(&solver) < 0.0);
+        solver.wind.clear();
+        assert_eq!(drift(&solver), 0.0);
+    }
+
+    #[test]
+    fn test_head_and_tail_wind() {
+        let mut solver = script_solver();
+        let load = load(0.5, DragModel::G1, 2800.0, 150.0);
+        let mut at_1000 = |direction: f64| {
+            solver.wind = vec![WindZone::uniform(Wind {
+                speed: 20.0,
+                direction,
+                vertical: 0.0,
+            })];
+            solver.trajectory(&load, bore(0.01), &[1000.0])[0]
+        };
+        let head = at_1000(0.0);
+        let tail = at_1000(180.0);
+        assert!(head.velocity < tail.velocity);
+        assert!(head.drop < tail.drop);
+        assert!(head.drift.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rising_air_lifts_the_bullet() {
+        let mut solver = script_solver();
+        let load = load(0.5, DragModel::G1, 2800.0, 150.0);
+        solver.wind.clear();
+        let still = solver.trajectory(&load, bore(0.01), &[1000.0])[0];
+        solver.wind = vec![WindZone::uniform(Wind {
+            vertical: 5.0,
+            ..Wind::default()
+        })];
+        let rising = solver.trajectory(&load, bore(0.01), &[1000.0])[0];
+        assert!(rising.drop > still.drop);
+    }
+
+    #[test]
+    fn test_wind_zones() {
+        let mut solver = script_solver();
+        let load = load(0.5, DragModel::G1, 2800.0, 150.0);
+        let ranges = [300.0, 600.0, 1000.0];
+
+        // Wind only past 600 yd leaves the first 600 yd untouched
+        solver.wind = vec![WindZone {
+            start: 600.0,
+            wind: crosswind(10.0),
+        }];
+        let far = solver.trajectory(&load, bore(0.01), &ranges);
+        assert_eq!(far[0].drift, 0.0);
+        assert!(far[1].drift.abs() < 1e-9);
+        assert!(far[2].drift > 0.0);
+
+        // Wind near the muzzle moves the bullet more than the same wind
+        // near the target
+        solver.wind = vec![
+            WindZone::uniform(crosswind(10.0)),
+            WindZone {
+                start: 600.0,
+                wind: Wind::default(),
+            },
+        ];
+        let near = solver.trajectory(&load, bore(0.01), &ranges);
+        assert!(near[2].drift > far[2].drift);
+
+        // Zones add up to the uniform wind
+        solver.wind = vec![WindZone::uniform(crosswind(10.0))];
+        let uniform = solver.trajectory(&load, bore(0.01), &ranges);
+        assert!((near[2].drift + far[2].drift - uniform[2].drift).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_spin_drift() {
+        let mut solver = script_solver();
+        solver.wind.clear();
+        let mut load = load(0.462, DragModel::G1, 2650.0, 168.0);
+        let at_1000 =
+            |solver: &Solver, load: &Load| solver.trajectory(load, bore(0.01), &[1000.0])[0];
+        assert_eq!(at_1000(&solver, &load).spin_drift, 0.0);
+
+        // 168gr .308 SMK from a 1:12 right-hand barrel drifts about 10"
+        // right at 1000 yd
+        load.diameter = 0.308;
+        load.length = 1.215;
+        solver.rifle.twist = 12.0;
+        let right = at_1000(&solver, &load);
+        assert!((right.spin_drift - 10.0).abs() < 1.5, "{right:?}");
+        assert_eq!(right.drift, 0.0);
+        assert_eq!(right.windage(), right.spin_drift);
+
+        solver.rifle.twist_direction = TwistDirection::Left;
+        assert_eq!(at_1000(&solver, &load).spin_drift, -right.spin_drift);
+    }
+
+    #[test]
+    fn test_aerodynamic_jump() {
+        let mut solver = script_solver();
+        solver.rifle.twist = 12.0;
+        let mut load = load(0.462, DragModel::G1, 2650.0, 168.0);
+        let zero = solver.zero(&load).unwrap();
+        let at_1000 = |solver: &Solver, load: &Load| solver.trajectory(load, zero, &[1000.0])[0];
+        // Unknown dimensions
+        assert_eq!(at_1000(&solver, &load).aerodynamic_jump, 0.0);
+
+        // 10 mph from the left throws a right-hand twist bullet down about
+        // 0.4 MOA, 4" at 1000 yd
+        load.diameter = 0.308;
+        load.length = 1.215;
+        let from_left = at_1000(&solver, &load);
+        assert!(
+            (from_left.aerodynamic_jump + 4.1).abs() < 0.3,
+            "{from_left:?}"
+        );
+
+        solver.wind = vec![WindZone::uniform(crosswind(-10.0))];
+        let from_right = at_1000(&solver, &load);
+        assert!((from_right.aerodynamic_jump + from_left.aerodynamic_jump).abs() < 1e-9);
+        // Mirrored wind, mirrored drift; only the jump tells them apart
+        let jump = from_right.aerodynamic_jump - from_left.aerodynamic_jump;
+        assert!((from_right.drop - from_left.drop - jump).abs() < 1e-6);
+
+        // Only the wind at the muzzle counts
+        solver.wind = vec![WindZone {
+            start: 100.0,
+            wind: crosswind(10.0),
+        }];
+        assert_eq!(at_1000(&solver, &load).aerodynamic_jump, 0.0);
+    }
+
+    #[test]
+    fn test_earth_rotation() {
+        let mut solver = script_solver();
+        solver.wind.clear();
+        let load = load(0.462, DragModel::G1, 2650.0, 168.0);
+        let zero = solver.zero(&load).unwrap();
+        let at_1000 = |solver: &Solver| solver.trajectory(&load, zero, &[1000.0])[0];
+        let still = at_1000(&solver);
+        assert_eq!((still.coriolis, still.eotvos), (0.0, 0.0));
+
+        // Firing east at 45° N both effects come to a little under
+        // Ω · range · time of flight · sin 45°, 3.3", as drag damps the
+        // sideways and vertical motion they add
+        solver.earth_rotation = Some(EarthRotation {
+            latitude: 45.0,
+            azimuth: 90.0,
+        });
+        let east = at_1000(&solver);
+        assert!((east.coriolis - 3.0).abs() < 0.3, "{east:?}");
+        assert!((east.eotvos - east.coriolis).abs() < 0.01, "{east:?}");
+        assert!((east.drop - east.eotvos - still.drop).abs() < 1e-3);
+        assert!(east.drift.abs() < 1e-9);
+        assert_eq!(east.windage(), east.coriolis);
+
+        // Firing west the bullet lands low instead
+        solver.earth_rotation = Some(EarthRotation {
+            latitude: 45.0,
+            azimuth: 270.0,
+        });
+        let west = at_1000(&solver);
+        assert!((west.eotvos + east.eotvos).abs() < 0.1);
+        assert!((west.coriolis - east.coriolis).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_inclined_shot_in_vacuum() {
+        // With next to no drag the flight is a parabola, with gravity split
+        // between the line of sight and across it
+        let mut solver = script_solver();
+        solver.wind.clear();
+        solver.look_angle = 30.0;
+        let vacuum = load(1e9, DragModel::G1, 2800.0, 168.0);
+        let elevation = 0.002;
+        let points = solver.trajectory(&vacuum, bore(elevation), &[500.0]);
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let range = 500.0 * FEET_PER_YARD;
+        let (along, across) = (2800.0 * elevation.cos(), 2800.0 * elevation.sin());
+        // Solve range = along · t − g sin θ t² / 2 for t
+        let a = GRAVITY * sin / 2.0;
+        let time = (along - (along * along - 4.0 * a * range).sqrt()) / (2.0 * a);
+        let height = -1.9 / INCHES_PER_FOOT + across * time - GRAVITY * cos * time * time / 2.0;
+        assert!((points[0].time_of_flight - time).abs() < 1e-6, "{points:?}");
+        assert!(
+            (points[0].drop - height * INCHES_PER_FOOT).abs() < 1e-3,
+            "{points:?}"
+        );
+    }
+
+    #[test]
+    fn test_inclined_shots() {
+        let mut solver = script_solver();
+        solver.wind.clear();
+        let load = load(0.462, DragModel::G1, 2650.0, 168.0);
+        let zero = solver.zero(&load).unwrap();
+        let at_600 = |solver: &Solver| solver.trajectory(&load, zero, &[600.0])[0];
+        let level = at_600(&solver);
+        assert_eq!(level.riflemans_rule, level.drop);
+
+        // Up or down 30°, the bullet hits 16–17" higher than on the flat.
+        // The rifleman's rule gets most of the way but over-corrects by a few
+        // inches: holding for it would shoot high
+        for look_angle in [30.0, -30.0] {
+            solver.look_angle = look_angle;
+            let inclined = at_600(&solver);
+            let rise = inclined.drop - level.drop;
+            assert!((15.5..18.0).contains(&rise), "{inclined:?}");
+            let error = inclined.riflemans_rule - inclined.drop;
+            assert!(error > 0.0 && error < 8.0, "{inclined:?}");
+        }
+        // Downhill gravity speeds the bullet up and uphill slows it
+        solver.look_angle = 30.0;
+        let uphill = at_600(&solver);
+        solver.look_angle = -30.0;
+        let downhill = at_600(&solver);
+        assert!(downhill.velocity > level.velocity && level.velocity > uphill.velocity);
+        assert!(downhill.drop > uphill.drop);
+    }
+
+    #[test]
+    fn test_zero_beyond_old_window() {
+        // A subsonic 220gr .30 zeroed at 1000 yd needs more than the script's
+        // 0.05 rad ceiling
+        let mut solver = script_solver();
+        solver.wind.clear();
+        solver.rifle.zero_range = 1000.0;
+        let subsonic = load(0.6, DragModel::G1, 1050.0, 220.0);
+        let zero = solver.zero(&subsonic).unwrap();
+        assert!(zero.elevation > 0.05, "{zero:?}");
+        let point = solver.trajectory(&subsonic, zero, &[1000.0])[0];
+        assert!(point.drop.abs() < 1e-3, "{point:?}");
+    }
+
+    #[test]
+    fn test_zero_offset() {
+        let mut solver = script_solver();
+        solver.wind.clear();
+        solver.rifle.zero_height = 1.0;
+        solver.rifle.zero_windage = -0.5;
+        let load = load(0.462, DragModel::G1, 2650.0, 168.0);
+        let zero = solver.zero(&load).unwrap();
+        let point = solver.trajectory(&load, zero, &[100.0])[0];
+        assert!((point.drop - 1.0).abs() < 1e-3, "{point:?}");
+        assert!((point.drift + 0.5).abs() < 1e-6, "{point:?}");
+
+        // Far below the line of sight needs the bore pointed down
+        solver.rifle.zero_height = -60.0;
+        assert!(solver.zero(&load).unwrap().elevation < 0.0);
+    }
+
+    #[test]
+    fn test_zero_failures() {
+        let mut solver = script_solver();
+        let slow = load(0.1, DragModel::G1, 800.0, 40.0);
+        solver.rifle.zero_range = 5000.0;
+        assert!(solver.zero(&slow).is_err());
+        solver.rifle.zero_range = 0.0;
+        assert!(solver.zero(&slow).is_err());
+    }
+
+    #[test]
+    fn test_zero_atmosphere() {
+        let mut solver = script_solver();
+        solver.wind.clear();
+        let load = load(0.462, DragModel::G1, 2650.0, 168.0);
+        let cold = Atmosphere {
+            temperature: 0.0,
+            pressure: 30.5,
+            ..Atmosphere::default()
+        };
+        let zeroed_here = solver.zero(&load).unwrap();
+
+        // Zeroed on a cold day, the rifle shoots high in warmer, thinner air
+        solver.zero_atmosphere = Some(cold);
+        let zeroed_cold = solver.zero(&load).unwrap();
+        assert!(zeroed_cold.elevation > zeroed_here.elevation);
+        let cold_solver = Solver {
+            atmosphere: cold,
+            zero_atmosphere: None,
+            ..solver.clone()
+        };
+        assert_eq!(cold_solver.zero(&load).unwrap(), zeroed_cold);
+        let point = solver.trajectory(&load, zeroed_cold, &[100.0])[0];
+        assert!(point.drop > 0.0, "{point:?}");
+    }
+
+    #[test]
+    fn test_equivalent_zero() {
+        // The script's loads on the primary load's 100 yd zero
+        let solver = script_solver();
+        let primary = load(0.254, DragModel::G7, 2725.0, 175.0);
+        let zero = solver.zero(&primary).unwrap();
+        let own = solver.crossings(&primary, zero, 0.0, 1000.0);
+        // A 100 yd zero barely rises above the line of sight, and the zero
+        // only holds to a thousandth of an inch where it crosses shallowly
+        assert!((own.far.unwrap() - 100.0).abs() < 0.2, "{own:?}");
+        assert!((own.near.unwrap() - 75.0).abs() < 1.0, "{own:?}");
+
+        // A faster, flatter load crosses sooner and comes down far later
+        let fast = load(0.35, DragModel::G1, 3125.0, 130.0);
+        let equivalent = solver.crossings(&fast, zero, 0.0, 1000.0);
+        assert!(
+            equivalent.near.unwrap() < own.near.unwrap(),
+            "{equivalent:?}"
+        );
+        assert!(equivalent.far.unwrap() > 150.0, "{equivalent:?}");
+
+        // A slow one never reaches the line of sight
+        let slow = load(0.2, DragModel::G1, 1500.0, 150.0);
+        assert_eq!(
+            solver.crossings(&slow, zero, 0.0, 1000.0),
+            Crossings::default()
+        );
+    }
+
+    #[test]
+    fn test_unreached_ranges_are_left_out() {
+        let solver = script_solver();
+        let slow = load(0.1, DragModel::G1, 800.0, 40.0);
+        let points = solver.trajectory(&slow, bore(0.01), &[100.0, 5000.0]);
+        assert_eq!(points.len(), 1);
+    }
+}
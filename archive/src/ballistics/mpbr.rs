@@ -0,0 +1,207 @@
+//! Maximum point-blank range: the longest stretch over which a load stays
+//! within a vital zone without holdover, as in the Elm app's MPBR tool.
+//!
+//! The best zero lifts the trajectory's highest point to the top of the
+//! vital zone. The bullet then stays in the zone until it falls through the
+//! bottom, and that range is the MPBR. Where the Elm app tries zero ranges
+//! in 5 yd steps, this solves for the bore elevation directly.
+
+use super::{Load, MAX_ZERO_ELEVATION, Solver, Zero};
+use crate::i18n::tr;
+
+/// Farthest range searched, in yards, as in the Elm app.
+const SEARCH_RANGE: f64 = 1500.0;
+/// How close the highest point comes to the top of the vital zone, in
+/// inches.
+const TOLERANCE: f64 = 1e-3;
+const MAX_ITERATIONS: usize = 100;
+
+/// The zero that gives the longest point-blank range, and what it gives.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointBlankRange {
+    pub zero: Zero,
+    /// Where the bullet rises through the line of sight, in yards.
+    pub near_zero: f64,
+    /// Where it falls back through it, in yards: the range to zero at.
+    pub far_zero: f64,
+    /// Highest point of the trajectory above the line of sight, in inches:
+    /// the vital zone's radius.
+    pub max_ordinate: f64,
+    /// Range of the highest point, in yards.
+    pub max_ordinate_range: f64,
+    /// Where the bullet rises into the vital zone, in yards; zero unless the
+    /// sight sits higher above the bore than the zone's radius.
+    pub near_limit: f64,
+    /// Where it falls out of the bottom of the zone, in yards: the MPBR.
+    pub far_limit: f64,
+}
+
+impl Solver {
+    /// The longest point-blank range of `load` on a vital zone of
+    /// `vital_zone` inches across.
+    pub fn point_blank_range(
+        &self,
+        load: &Load,
+        vital_zone: f64,
+    ) -> Result<PointBlankRange, String> {
+        let radius = vital_zone / 2.0;
+        if !radius.is_finite() || radius <= 0.0 {
+            return Err(tr("The vital zone must be greater than zero.").to_string());
+        }
+        // Highest point rises with the bore, so bracket and bisect it
+        let bore = |elevation| Zero {
+            elevation,
+            windage: 0.0,
+        };
+        let rise = |elevation| self.max_ordinate(load, bore(elevation)).1 - radius;
+        let (mut low, mut high) = (0.0, 0.001);
+        while rise(high) < 0.0 {
+            if high >= MAX_ZERO_ELEVATION {
+                return Err(tr("The bullet can't rise to the top of the vital zone.").to_string());
+            }
+            low = high;
+            high = (2.0 * high).min(MAX_ZERO_ELEVATION);
+        }
+        let elevation = (0..MAX_ITERATIONS)
+            .find_map(|_| {
+                let elevation = (low + high) / 2.0;
+                let rise = rise(elevation);
+                if rise.abs() < TOLERANCE {
+                    return Some(elevation);
+                }
+                if rise > 0.0 {
+                    high = elevation;
+                } else {
+                    low = elevation;
+                }
+                None
+            })
+            .ok_or_else(|| tr("The point-blank range search did not converge.").to_string())?;
+
+        let zero = bore(elevation);
+        let (max_ordinate_range, max_ordinate) = self.max_ordinate(load, zero);
+        let zeros = self.crossings(load, zero, 0.0, SEARCH_RANGE);
+        let limits = self.crossings(load, zero, -radius, SEARCH_RANGE);
+        match (zeros.near, zeros.far, limits.far) {
+            (Some(near_zero), Some(far_zero), Some(far_limit)) => Ok(PointBlankRange {
+                zero,
+                near_zero,
+                far_zero,
+                max_ordinate,
+                max_ordinate_range,
+                near_limit: limits.near.unwrap_or(0.0),
+                far_limit,
+            }),
+            _ => Err(tr("The point-blank range is beyond the range searched.").to_string()),
+        }
+    }
+
+    /// Range in yards and height in inches of the highest point above the
+    /// line of sight, sampled every yard.
+    fn max_ordinate(&self, load: &Load, zero: Zero) -> (f64, f64) {
+        let ranges: Vec<f64> = (0..=SEARCH_RANGE as usize)
+            .map(|range| range as f64)
+            .collect();
+        self.trajectory(load, zero, &ranges)
+            .iter()
+            .map(|point| (point.range, point.drop))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((0.0, f64::NEG_INFINITY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Atmosphere, DragModel, Rifle, TwistDirection};
+    use super::*;
+
+    fn solver(sight_height: f64) -> Solver {
+        Solver {
+            rifle: Rifle {
+                sight_height,
+                zero_range: 100.0,
+                twist: 0.0,
+                twist_direction: TwistDirection::Right,
+                zero_height: 0.0,
+                zero_windage: 0.0,
+            },
+            atmosphere: Atmosphere::default(),
+            wind: Vec::new(),
+            earth_rotation: None,
+            look_angle: 0.0,
+            zero_atmosphere: None,
+        }
+    }
+
+    fn load(bc: f64, muzzle_velocity: f64) -> Load {
+        Load {
+            name: String::new(),
+            weight: 150.0,
+            bc,
+            drag_model: DragModel::G1,
+            muzzle_velocity,
+            drag_curve: None,
+            bc_bands: Vec::new(),
+            diameter: 0.0,
+            length: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_point_blank_range() {
+        // .30-06 150gr at 2900 fps on an 8" vital zone: published MPBR
+        // tables give a zero near 260 yd and an MPBR near 310 yd
+        let solver = solver(1.5);
+        let mpbr = solver.point_blank_range(&load(0.4, 2900.0), 8.0).unwrap();
+        assert!((mpbr.max_ordinate - 4.0).abs() < TOLERANCE, "{mpbr:?}");
+        assert!((mpbr.far_zero - 260.0).abs() < 15.0, "{mpbr:?}");
+        assert!((mpbr.far_limit - 310.0).abs() < 15.0, "{mpbr:?}");
+        assert!(mpbr.near_zero < mpbr.max_ordinate_range);
+        assert!(mpbr.max_ordinate_range < mpbr.far_zero && mpbr.far_zero < mpbr.far_limit);
+        assert_eq!(mpbr.near_limit, 0.0);
+
+        // Zeroed there, the rifle hits on the line of sight at the zero range
+        let zeroed = Solver {
+            rifle: Rifle {
+                zero_range: mpbr.far_zero,
+                ..solver.rifle
+            },
+            ..solver.clone()
+        };
+        let zero = zeroed.zero(&load(0.4, 2900.0)).unwrap();
+        assert!((zero.elevation - mpbr.zero.elevation).abs() < 1e-5);
+
+        // A faster, flatter load reaches farther
+        let flatter = solver.point_blank_range(&load(0.5, 3200.0), 8.0).unwrap();
+        assert!(flatter.far_limit > mpbr.far_limit + 30.0);
+    }
+
+    #[test]
+    fn test_tall_sight() {
+        // A 3" sight over a 4" zone starts below the zone
+        let mpbr = solver(3.0)
+            .point_blank_range(&load(0.4, 2900.0), 4.0)
+            .unwrap();
+        assert!(
+            mpbr.near_limit > 0.0 && mpbr.near_limit < mpbr.near_zero,
+            "{mpbr:?}"
+        );
+    }
+
+    #[test]
+    fn test_failures() {
+        let solver = solver(1.5);
+        assert!(solver.point_blank_range(&load(0.4, 2900.0), 0.0).is_err());
+        // A huge zone puts the MPBR past the search range
+        assert!(
+            solver
+                .point_blank_range(&load(0.4, 2900.0), 2000.0)
+                .is_err()
+        );
+        // Even at 45° a slow bullet can't rise 400 ft above the sight line
+        assert_eq!(
+            solver.point_blank_range(&load(0.4, 150.0), 10_000.0),
+            Err(tr("The bullet can't rise to the top of the vital zone.").to_string())
+        );
+    }
+}
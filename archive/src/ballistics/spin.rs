@@ -0,0 +1,136 @@
+//! Gyroscopic stability and spin drift.
+//!
+//! A spinning bullet's nose points slightly to the side its spin carries it,
+//! so it drifts that way as it falls: right for a right-hand twist. The drift
+//! follows Litz's fit to measured data, scaled by the stability factor from
+//! the Miller twist rule.
+//!
+//! A crosswind at the muzzle also tips the spinning bullet up or down as it
+//! leaves the barrel. Litz's estimate of this aerodynamic jump is an angle
+//! proportional to the crosswind.
+
+use serde::{Deserialize, Serialize};
+
+use super::RADIANS_PER_MOA;
+
+/// Which way the rifling turns, seen from behind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TwistDirection {
+    #[default]
+    Right,
+    Left,
+}
+
+/// The Miller rule's reference muzzle velocity, in ft/s.
+const MILLER_VELOCITY: f64 = 2800.0;
+
+/// Gyroscopic stability factor by the Miller twist rule for a bullet of
+/// `weight` grains, `diameter` and `length` inches, fired at `velocity` ft/s
+/// from a barrel with one turn in `twist` inches, in air of `density_ratio`.
+/// Bullets need more than 1 to fly point first and 1.5 to be fully stable.
+/// `None` if a dimension is unknown.
+pub fn stability_factor(
+    weight: f64,
+    diameter: f64,
+    length: f64,
+    twist: f64,
+    velocity: f64,
+    density_ratio: f64,
+) -> Option<f64> {
+    if [diameter, length, twist]
+        .iter()
+        .any(|value| value.is_nan() || *value <= 0.0)
+    {
+        return None;
+    }
+    let twist_calibers = twist / diameter;
+    let length_calibers = length / diameter;
+    let standard = 30.0 * weight
+        / (twist_calibers.powi(2)
+            * diameter.powi(3)
+            * length_calibers
+            * (1.0 + length_calibers.powi(2)));
+    Some(standard * (velocity / MILLER_VELOCITY).cbrt() / density_ratio)
+}
+
+/// Spin drift after `time_of_flight` seconds for a bullet with stability
+/// factor `stability`, in inches; positive to the right.
+pub fn spin_drift(stability: f64, time_of_flight: f64, direction: TwistDirection) -> f64 {
+    let drift = 1.25 * (stability + 1.2) * time_of_flight.powf(1.83);
+    match direction {
+        TwistDirection::Right => drift,
+        TwistDirection::Left => -drift,
+    }
+}
+
+/// Vertical launch angle from aerodynamic jump, in radians; positive up.
+/// `length_calibers` is the bullet's length over its diameter and
+/// `crosswind` the muzzle crosswind in mph, positive from the left.
+pub fn aerodynamic_jump(
+    stability: f64,
+    length_calibers: f64,
+    crosswind: f64,
+    direction: TwistDirection,
+) -> f64 {
+    // MOA per mph of crosswind from the right, for a right-hand twist
+    let per_mph = 0.01 * stability - 0.0024 * length_calibers + 0.032;
+    let jump = -per_mph * crosswind * RADIANS_PER_MOA;
+    match direction {
+        TwistDirection::Right => jump,
+        TwistDirection::Left => -jump,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_miller_rule() {
+        // 168gr .308 SMK, 1.215" long, from a 1:12 barrel at 2650 fps in
+        // standard air: Sg about 1.71
+        let sg = stability_factor(168.0, 0.308, 1.215, 12.0, 2650.0, 1.0).unwrap();
+        assert!((sg - 1.71).abs() < 0.01, "{sg}");
+
+        // Faster twist, thinner air and more speed all add stability
+        let faster = stability_factor(168.0, 0.308, 1.215, 10.0, 2650.0, 1.0).unwrap();
+        let thinner = stability_factor(168.0, 0.308, 1.215, 12.0, 2650.0, 0.8).unwrap();
+        let quicker = stability_factor(168.0, 0.308, 1.215, 12.0, 2900.0, 1.0).unwrap();
+        assert!(faster > sg && thinner > sg && quicker > sg);
+
+        assert_eq!(stability_factor(168.0, 0.0, 1.215, 12.0, 2650.0, 1.0), None);
+        assert_eq!(
+            stability_factor(168.0, 0.308, 1.215, 0.0, 2650.0, 1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_spin_drift() {
+        // Sg 1.74 and 1.7 s to 1000 yd: about 9.7" right
+        let drift = spin_drift(1.74, 1.7, TwistDirection::Right);
+        assert!((drift - 9.7).abs() < 0.1, "{drift}");
+        assert_eq!(spin_drift(1.74, 1.7, TwistDirection::Left), -drift);
+        assert_eq!(spin_drift(1.74, 0.0, TwistDirection::Right), 0.0);
+    }
+
+    #[test]
+    fn test_aerodynamic_jump() {
+        // Sg 1.71, 3.9 calibers long: 0.040 MOA per mph, so a 10 mph wind
+        // from the right lifts a right-hand twist bullet 0.40 MOA
+        let jump = aerodynamic_jump(1.71, 3.945, -10.0, TwistDirection::Right);
+        assert!((jump / RADIANS_PER_MOA - 0.40).abs() < 0.01, "{jump}");
+        assert_eq!(
+            aerodynamic_jump(1.71, 3.945, 10.0, TwistDirection::Right),
+            -jump
+        );
+        assert_eq!(
+            aerodynamic_jump(1.71, 3.945, -10.0, TwistDirection::Left),
+            -jump
+        );
+        assert_eq!(
+            aerodynamic_jump(1.71, 3.945, 0.0, TwistDirection::Right),
+            0.0
+        );
+    }
+}
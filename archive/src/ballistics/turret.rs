@@ -0,0 +1,188 @@
+//! Sight adjustments: holds as angles in the units scope turrets are marked
+//! in, and as whole clicks.
+//!
+//! The script prints drop in MOA of 1.047" at 100 yd. Scopes are marked in
+//! true MOA, in shooter's MOA of exactly 1" at 100 yd (inches per hundred
+//! yards, IPHY), or in milliradians. Over 1000 yd the two MOAs differ by
+//! nearly 5%, a click every 20.
+
+use serde::{Deserialize, Serialize};
+
+use super::{FEET_PER_YARD, INCHES_PER_FOOT, RADIANS_PER_MOA};
+use crate::i18n::{format_number, tr};
+
+/// Unit a turret adjusts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AngleUnit {
+    /// A sixtieth of a degree: 1.047" at 100 yd.
+    #[default]
+    TrueMoa,
+    /// 1" at 100 yd.
+    ShootersMoa,
+    /// A milliradian: 3.6" at 100 yd.
+    Mil,
+}
+
+impl AngleUnit {
+    pub const ALL: [AngleUnit; 3] = [AngleUnit::TrueMoa, AngleUnit::ShootersMoa, AngleUnit::Mil];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AngleUnit::TrueMoa => "MOA",
+            AngleUnit::ShootersMoa => "IPHY",
+            AngleUnit::Mil => "mil",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            AngleUnit::TrueMoa => tr("True minutes of angle, 1.047 in at 100 yd"),
+            AngleUnit::ShootersMoa => tr("Shooter's MOA, 1 in at 100 yd"),
+            AngleUnit::Mil => tr("Milliradians, 3.6 in at 100 yd"),
+        }
+    }
+
+    /// Click values turrets in this unit come with, finest last.
+    pub fn clicks(&self) -> &'static [f64] {
+        match self {
+            AngleUnit::TrueMoa | AngleUnit::ShootersMoa => &[0.25, 0.125],
+            AngleUnit::Mil => &[0.1, 0.05],
+        }
+    }
+
+    /// `click` as turrets are marked: "1/4 MOA", "0.1 mil".
+    pub fn click_label(&self, click: f64) -> String {
+        match self {
+            AngleUnit::TrueMoa | AngleUnit::ShootersMoa => {
+                format!("1/{} {}", (1.0 / click).round(), self.label())
+            }
+            AngleUnit::Mil => {
+                let decimals = if click >= 0.1 { 1 } else { 2 };
+                format!("{} {}", format_number(click, decimals), self.label())
+            }
+        }
+    }
+
+    /// The angle `deflection` inches subtends at `range` yards, in this
+    /// unit.
+    pub fn angle(&self, deflection: f64, range: f64) -> f64 {
+        let ratio = deflection / (range * FEET_PER_YARD * INCHES_PER_FOOT);
+        match self {
+            AngleUnit::TrueMoa => ratio.atan() / RADIANS_PER_MOA,
+            // Inches per hundred yards is a ratio, not an angle
+            AngleUnit::ShootersMoa => ratio * 3600.0,
+            AngleUnit::Mil => ratio.atan() * 1000.0,
+        }
+    }
+}
+
+/// A scope's elevation and windage turrets.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Turret {
+    pub unit: AngleUnit,
+    /// Adjustment per click, in `unit`.
+    pub click: f64,
+}
+
+impl Default for Turret {
+    /// A 1/4 MOA scope.
+    fn default() -> Self {
+        Self {
+            unit: AngleUnit::TrueMoa,
+            click: 0.25,
+        }
+    }
+}
+
+impl Turret {
+    /// Switches to `unit`, keeping the click value if turrets in that unit
+    /// come with it and taking the coarsest one otherwise.
+    pub fn set_unit(&mut self, unit: AngleUnit) {
+        self.unit = unit;
+        if !unit.clicks().contains(&self.click) {
+            self.click = unit.clicks()[0];
+        }
+    }
+
+    /// The adjustment that corrects for a miss of `deflection` inches at
+    /// `range` yards: positive up for a drop below the line of sight,
+    /// positive right for a drift to the left.
+    pub fn adjustment(&self, deflection: f64, range: f64) -> Adjustment {
+        let angle = -self.unit.angle(deflection, range);
+        let clicks = (angle / self.click).round();
+        Adjustment {
+            angle,
+            clicks: clicks as i64,
+            residual: angle - clicks * self.click,
+        }
+    }
+}
+
+/// A correction, dialled to the nearest click.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Adjustment {
+    /// Exact correction, in the turret's unit.
+    pub angle: f64,
+    pub clicks: i64,
+    /// What the clicks leave out, in the turret's unit: at most half a
+    /// click, positive if they fall short.
+    pub residual: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units() {
+        // 10" at 100 yd
+        let true_moa = AngleUnit::TrueMoa.angle(10.0, 100.0);
+        assert!((true_moa - 9.549).abs() < 1e-3, "{true_moa}");
+        assert!((AngleUnit::ShootersMoa.angle(10.0, 100.0) - 10.0).abs() < 1e-12);
+        let mils = AngleUnit::Mil.angle(36.0, 1000.0);
+        assert!((mils - 1.0).abs() < 1e-6, "{mils}");
+        // Within the script's rounding of a true MOA to 1.047"
+        assert!((AngleUnit::TrueMoa.angle(10.47, 1000.0) - 1.0).abs() < 1e-3);
+
+        assert_eq!(AngleUnit::ShootersMoa.click_label(0.125), "1/8 IPHY");
+        assert_eq!(AngleUnit::Mil.click_label(0.1), "0.1 mil");
+        assert_eq!(AngleUnit::Mil.click_label(0.05), "0.05 mil");
+    }
+
+    #[test]
+    fn test_adjustment() {
+        let turret = Turret::default();
+        // 52.35" low at 500 yd: 10 MOA up, 40 clicks
+        let up = turret.adjustment(-52.35, 500.0);
+        assert!((up.angle - 10.0).abs() < 0.01, "{up:?}");
+        assert_eq!(up.clicks, 40);
+        assert!(up.residual.abs() < 0.01);
+
+        // 10" right at 100 yd: 9.55 MOA left, 38 clicks and 0.05 left over
+        let left = turret.adjustment(10.0, 100.0);
+        assert_eq!(left.clicks, -38);
+        assert!((left.residual + 0.049).abs() < 1e-3, "{left:?}");
+        assert!((left.angle - (left.clicks as f64 * 0.25 + left.residual)).abs() < 1e-12);
+
+        let fine = Turret {
+            unit: AngleUnit::Mil,
+            click: 0.05,
+        };
+        let left = fine.adjustment(10.0, 100.0);
+        assert_eq!(left.clicks, -56);
+        assert!(left.residual.abs() <= 0.025);
+    }
+
+    #[test]
+    fn test_set_unit() {
+        let mut turret = Turret {
+            unit: AngleUnit::TrueMoa,
+            click: 0.125,
+        };
+        turret.set_unit(AngleUnit::ShootersMoa);
+        assert_eq!(turret.click, 0.125);
+        turret.set_unit(AngleUnit::Mil);
+        assert_eq!(turret.click, 0.1);
+    }
+}
@@ -0,0 +1,132 @@
+//! Wind, by speed and direction, over bands of the range.
+
+use serde::{Deserialize, Serialize};
+
+use super::{FEET_PER_YARD, FPS_PER_MPH};
+
+/// Wind as it is called on the range.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Wind {
+    /// Speed, in mph.
+    pub speed: f64,
+    /// Direction the wind blows from, in degrees clockwise from the target:
+    /// 0° is a headwind, 90° from the right, 180° a tailwind and 270° from
+    /// the left, as in the Elm app.
+    pub direction: f64,
+    /// Vertical speed, in mph; positive for air rising.
+    #[serde(default)]
+    pub vertical: f64,
+}
+
+impl Wind {
+    /// Component across the line of fire, in mph; positive from the left,
+    /// pushing the bullet right.
+    pub fn crosswind(&self) -> f64 {
+        -self.speed * self.direction.to_radians().sin()
+    }
+
+    /// Component along the line of fire, in mph; positive for a headwind.
+    pub fn headwind(&self) -> f64 {
+        self.speed * self.direction.to_radians().cos()
+    }
+
+    /// Velocity of the air, in ft/s, in the solver's frame: x downrange, y
+    /// up and z to the right.
+    fn velocity(&self) -> [f64; 3] {
+        [
+            -self.headwind() * FPS_PER_MPH,
+            self.vertical * FPS_PER_MPH,
+            self.crosswind() * FPS_PER_MPH,
+        ]
+    }
+}
+
+/// Wind that blows from `start` yards downrange up to the next zone, or to
+/// the end of the flight.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindZone {
+    /// Range the zone starts at, in yards.
+    pub start: f64,
+    pub wind: Wind,
+}
+
+impl WindZone {
+    /// The same wind over the whole range.
+    pub fn uniform(wind: Wind) -> Self {
+        Self { start: 0.0, wind }
+    }
+}
+
+/// Wind zones in the form the solver looks them up in: air velocity in ft/s
+/// from each zone's start in feet, sorted by start. Air short of the first
+/// zone is still.
+pub(super) struct WindProfile(Vec<(f64, [f64; 3])>);
+
+impl WindProfile {
+    pub fn new(zones: &[WindZone]) -> Self {
+        let mut profile: Vec<_> = zones
+            .iter()
+            .map(|zone| (zone.start * FEET_PER_YARD, zone.wind.velocity()))
+            .collect();
+        profile.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self(profile)
+    }
+
+    /// Air velocity `range` feet downrange.
+    pub fn at(&self, range: f64) -> [f64; 3] {
+        self.0
+            .iter()
+            .rev()
+            .find(|(start, _)| range >= *start)
+            .map_or([0.0; 3], |&(_, velocity)| velocity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wind(speed: f64, direction: f64) -> Wind {
+        Wind {
+            speed,
+            direction,
+            vertical: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_components() {
+        let from_left = wind(10.0, 270.0);
+        assert!((from_left.crosswind() - 10.0).abs() < 1e-12);
+        assert!(from_left.headwind().abs() < 1e-12);
+
+        let from_right_front = wind(10.0, 45.0);
+        assert!((from_right_front.crosswind() + 7.071).abs() < 0.001);
+        assert!((from_right_front.headwind() - 7.071).abs() < 0.001);
+
+        let tail = wind(10.0, 180.0);
+        assert!((tail.headwind() + 10.0).abs() < 1e-12);
+        // A tailwind moves the air downrange
+        assert!(tail.velocity()[0] > 0.0);
+    }
+
+    #[test]
+    fn test_profile_zones() {
+        let profile = WindProfile::new(&[
+            WindZone {
+                start: 600.0,
+                wind: wind(15.0, 270.0),
+            },
+            WindZone {
+                start: 200.0,
+                wind: wind(5.0, 270.0),
+            },
+        ]);
+        assert_eq!(profile.at(100.0 * FEET_PER_YARD), [0.0; 3]);
+        let crosswind = |yards: f64| profile.at(yards * FEET_PER_YARD)[2] / FPS_PER_MPH;
+        assert!((crosswind(200.0) - 5.0).abs() < 1e-12);
+        assert!((crosswind(599.0) - 5.0).abs() < 1e-12);
+        assert!((crosswind(1000.0) - 15.0).abs() < 1e-12);
+        assert_eq!(WindProfile::new(&[]).at(0.0), [0.0; 3]);
+    }
+}
@@ -0,0 +1,2314 @@
+//! The ballistics solver page: the rifle, the user's loads and the editor
+//! for them, and the trajectory table.
+
+use std::cmp::Ordering;
+
+use egui::{ComboBox, DragValue, RichText, Ui};
+use egui_plot::{Legend, Line, MarkerShape, Plot, PlotPoints, Points};
+use serde::{Deserialize, Serialize};
+
+use crate::app::Settings;
+use crate::ballistics::{
+    AngleUnit, Atmosphere, BcBand, Crossings, CurveFile, DragCurve, DragModel, EarthRotation, Load,
+    PressureReference, Rifle, Solver, TrajectoryPoint, Turret, TwistDirection, Wind, WindZone,
+};
+use crate::dope_card::{CardSize, DopeCard, DopeCardSetup};
+use crate::files::{self, FileOpener, FileType};
+use crate::i18n::{format_number, format_tick, parse_number, tr, tr_args};
+use crate::input::{NumericInput, Validation};
+use crate::units::{Quantity, UnitSystem};
+
+pub const BULLET_WEIGHT: NumericInput = NumericInput {
+    label: "Weight",
+    name: "Bullet weight",
+    quantity: Quantity::BulletWeight,
+    soft_min: 20.0,
+    soft_max: 300.0,
+};
+
+pub const LOAD_VELOCITY: NumericInput = NumericInput {
+    label: "Velocity",
+    name: "Muzzle velocity",
+    quantity: Quantity::Velocity,
+    soft_min: 1000.0,
+    soft_max: 4000.0,
+};
+
+pub const SIGHT_HEIGHT: NumericInput = NumericInput {
+    label: "Sight height",
+    name: "Sight height",
+    quantity: Quantity::SightHeight,
+    soft_min: 1.0,
+    soft_max: 3.0,
+};
+
+pub const ZERO_RANGE: NumericInput = NumericInput {
+    label: "Zero",
+    name: "Zero range",
+    quantity: Quantity::Range,
+    soft_min: 25.0,
+    soft_max: 200.0,
+};
+
+pub const TWIST: NumericInput = NumericInput {
+    label: "Twist",
+    name: "Twist rate",
+    quantity: Quantity::Twist,
+    soft_min: 3.0,
+    soft_max: 14.0,
+};
+
+pub const TABLE_MAX_RANGE: NumericInput = NumericInput {
+    label: "Max range",
+    name: "Table max range",
+    quantity: Quantity::Range,
+    soft_min: 200.0,
+    soft_max: 2000.0,
+};
+
+pub const TABLE_STEP: NumericInput = NumericInput {
+    label: "Step",
+    name: "Table step size",
+    quantity: Quantity::Range,
+    soft_min: 25.0,
+    soft_max: 200.0,
+};
+
+pub const DOPE_CARD_STEP: NumericInput = NumericInput {
+    label: "Step",
+    name: "Dope card step size",
+    quantity: Quantity::Range,
+    soft_min: 25.0,
+    soft_max: 200.0,
+};
+
+/// Keeps a tiny step from building an enormous table.
+const MAX_TABLE_ROWS: usize = 500;
+
+/// Stability factors below this leave the bullet short of full gyroscopic
+/// stability, costing BC.
+const FULL_STABILITY: f64 = 1.5;
+
+/// Farthest range searched for a load's zeros, in yards.
+const CROSSING_SEARCH_RANGE: f64 = 2000.0;
+
+/// Points along each line of the trajectory chart.
+const CHART_POINTS: usize = 200;
+
+/// Height of the trajectory chart, in points.
+const CHART_HEIGHT: f32 = 300.0;
+
+/// Steepest look angle the page accepts, in degrees, up or down.
+const MAX_LOOK_ANGLE: f64 = 60.0;
+
+/// Typical ballistic coefficients, in lb/in². BCs are quoted in these units
+/// whatever system the rest of the app uses.
+const BC_RANGE: std::ops::RangeInclusive<f64> = 0.1..=1.0;
+
+/// The loads the Elm app starts with.
+/// Short descriptions of how `loads` differ from `previous`, for the
+/// history list. Loads are only ever added at the end or removed one at a
+/// time.
+pub fn load_changes(loads: &[Load], previous: &[Load]) -> Vec<String> {
+    match loads.len().cmp(&previous.len()) {
+        Ordering::Greater => loads[previous.len()..]
+            .iter()
+            .map(|load| tr_args("Added {0}", &[&load.name]))
+            .collect(),
+        Ordering::Less => {
+            let removed = previous
+                .iter()
+                .zip(loads)
+                .position(|(old, new)| old != new)
+                .unwrap_or(loads.len());
+            vec![tr_args("Removed {0}", &[&previous[removed].name])]
+        }
+        Ordering::Equal => loads
+            .iter()
+            .zip(previous)
+            .filter(|(new, old)| new != old)
+            .map(|(load, _)| tr_args("Edited {0}", &[&load.name]))
+            .collect(),
+    }
+}
+
+pub fn default_loads() -> Vec<Load> {
+    vec![
+        Load {
+            name: "NAS3 175Gr LRX".to_string(),
+            weight: 175.0,
+            bc: 0.254,
+            drag_model: DragModel::G7,
+            muzzle_velocity: 2725.0,
+            drag_curve: None,
+            bc_bands: Vec::new(),
+            diameter: 0.0,
+            length: 0.0,
+        },
+        Load {
+            name: "NAS3 150 TTSX".to_string(),
+            weight: 150.0,
+            bc: 0.44,
+            drag_model: DragModel::G1,
+            muzzle_velocity: 2950.0,
+            drag_curve: None,
+            bc_bands: Vec::new(),
+            diameter: 0.0,
+            length: 0.0,
+        },
+    ]
+}
+
+fn new_load() -> Load {
+    Load {
+        name: tr("New Load").to_string(),
+        weight: 150.0,
+        bc: 0.4,
+        drag_model: DragModel::G1,
+        muzzle_velocity: 2800.0,
+        drag_curve: None,
+        bc_bands: Vec::new(),
+        diameter: 0.0,
+        length: 0.0,
+    }
+}
+
+/// Checks a load before it is saved. Errors stop it being saved; warnings
+/// are shown alongside.
+fn validate(load: &Load, units: UnitSystem) -> Vec<Validation> {
+    let mut messages = Vec::new();
+    if load.name.trim().is_empty() {
+        messages.push(Validation::Error(
+            tr("Load names must not be empty.").to_string(),
+        ));
+    }
+    messages.push(BULLET_WEIGHT.validate(load.weight, units));
+    messages.push(if let Some(curve) = &load.drag_curve {
+        match curve.validate() {
+            Ok(()) if !load.diameter.is_finite() || load.diameter <= 0.0 => {
+                Validation::Error(tr("A drag curve needs the bullet's diameter.").to_string())
+            }
+            Ok(()) => Validation::Ok,
+            Err(message) => Validation::Error(message),
+        }
+    } else {
+        validate_bc(load.bc)
+    });
+    if load.drag_curve.is_none() {
+        for (i, band) in load.bc_bands.iter().enumerate() {
+            messages.push(validate_bc(band.bc));
+            if !band.velocity.is_finite() || band.velocity <= 0.0 {
+                messages.push(Validation::Error(
+                    tr("BC band velocities must be greater than zero.").to_string(),
+                ));
+            } else if load.bc_bands[..i]
+                .iter()
+                .any(|other| other.velocity == band.velocity)
+            {
+                messages.push(Validation::Error(tr_args(
+                    "Two BC bands start at {0}.",
+                    &[&Quantity::Velocity.format(units, band.velocity)],
+                )));
+            }
+        }
+    }
+    messages.push(LOAD_VELOCITY.validate(load.muzzle_velocity, units));
+    if load.diameter < 0.0 || load.length < 0.0 {
+        messages.push(Validation::Error(
+            tr("Bullet dimensions must not be negative.").to_string(),
+        ));
+    } else if (load.diameter > 0.0) != (load.length > 0.0) {
+        messages.push(Validation::Warning(
+            tr("Spin drift needs both the bullet's diameter and length.").to_string(),
+        ));
+    }
+    messages.dedup();
+    messages.retain(|message| *message != Validation::Ok);
+    messages
+}
+
+fn validate_bc(bc: f64) -> Validation {
+    if !bc.is_finite() || bc <= 0.0 {
+        Validation::Error(tr("BC must be greater than zero.").to_string())
+    } else if !BC_RANGE.contains(&bc) {
+        Validation::Warning(tr_args(
+            "A BC of {0} is outside the typical range of {1}–{2}.",
+            &[
+                &format_number(bc, 3),
+                &format_number(*BC_RANGE.start(), 1),
+                &format_number(*BC_RANGE.end(), 1),
+            ],
+        ))
+    } else {
+        Validation::Ok
+    }
+}
+
+/// A drag value editing `value`, stored in imperial units, in the user's
+/// units.
+fn quantity_value(value: &mut f64, quantity: Quantity, units: UnitSystem) -> DragValue<'_> {
+    let decimals = quantity.decimals(units);
+    DragValue::from_get_set(move |new| {
+        if let Some(new) = new {
+            *value = quantity.stored_value(units, new);
+        }
+        quantity.display_value(units, *value)
+    })
+    .speed(10f64.powi(-(decimals as i32)))
+    .max_decimals(decimals)
+    .custom_formatter(move |value, _| format_number(value, decimals))
+    .custom_parser(parse_number)
+    .suffix(format!(" {}", quantity.unit(units)))
+}
+
+fn bc_value(bc: &mut f64) -> DragValue<'_> {
+    DragValue::new(bc)
+        .speed(0.001)
+        .max_decimals(3)
+        .custom_formatter(|value, _| format_number(value, 3))
+        .custom_parser(parse_number)
+}
+
+/// Limits of the environment inputs, in stored units.
+const TEMPERATURE_RANGE: std::ops::RangeInclusive<f64> = -40.0..=130.0;
+const PRESSURE_RANGE: std::ops::RangeInclusive<f64> = 15.0..=32.0;
+const ALTITUDE_RANGE: std::ops::RangeInclusive<f64> = -1500.0..=15_000.0;
+const WIND_SPEED_RANGE: std::ops::RangeInclusive<f64> = 0.0..=60.0;
+
+/// What the trajectory chart plots against range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChartValue {
+    #[default]
+    Drop,
+    Windage,
+}
+
+impl ChartValue {
+    fn label(self) -> &'static str {
+        match self {
+            ChartValue::Drop => tr("Drop"),
+            ChartValue::Windage => tr("Windage"),
+        }
+    }
+}
+
+/// The rifle, conditions and trajectory table settings, saved between
+/// sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BallisticsSetup {
+    pub rifle: Rifle,
+    /// Farthest range in the trajectory table, in yards.
+    pub table_max_range: f64,
+    /// Range between rows of the trajectory table, in yards.
+    pub table_step: f64,
+    /// Whether to correct for the Earth's rotation.
+    pub earth_rotation_enabled: bool,
+    /// Kept while the correction is off, so turning it back on restores it.
+    pub earth_rotation: EarthRotation,
+    /// Whether the rifle was zeroed in other air than `atmosphere`.
+    pub zero_atmosphere_enabled: bool,
+    /// Temperature and station pressure the rifle was zeroed in. Kept while
+    /// unused, like `earth_rotation`.
+    pub zero_atmosphere: Atmosphere,
+    /// Air at the firing point. Only the temperature is used while
+    /// `use_density_altitude` is set.
+    pub atmosphere: Atmosphere,
+    /// Whether the air is given as a density altitude instead of pressure,
+    /// altitude and humidity.
+    pub use_density_altitude: bool,
+    /// Density altitude, in feet.
+    pub density_altitude: f64,
+    pub wind: Vec<WindZone>,
+    /// Index of the load the rifle is zeroed for. The others are fired with
+    /// its zero.
+    pub primary_load: usize,
+    /// Angle of the shot above the horizontal, in degrees; negative
+    /// downhill.
+    pub look_angle: f64,
+    /// The scope's turrets, which the table, chart and dope card give holds
+    /// for.
+    pub turret: Turret,
+    pub chart_value: ChartValue,
+    /// Whether the chart shows holds as angles in the turret's unit instead
+    /// of inches or cm.
+    pub chart_angular: bool,
+    pub dope_card: DopeCardSetup,
+}
+
+impl Default for BallisticsSetup {
+    /// The Elm app's defaults.
+    fn default() -> Self {
+        Self {
+            rifle: Rifle {
+                sight_height: 1.9,
+                zero_range: 100.0,
+                twist: 10.0,
+                twist_direction: TwistDirection::Right,
+                zero_height: 0.0,
+                zero_windage: 0.0,
+            },
+            table_max_range: 1000.0,
+            table_step: 100.0,
+            earth_rotation_enabled: false,
+            earth_rotation: EarthRotation::default(),
+            zero_atmosphere_enabled: false,
+            zero_atmosphere: Atmosphere {
+                temperature: 60.0,
+                pressure: 29.92,
+                ..Atmosphere::default()
+            },
+            atmosphere: Atmosphere {
+                temperature: 60.0,
+                pressure: 29.92,
+                ..Atmosphere::default()
+            },
+            use_density_altitude: false,
+            density_altitude: 0.0,
+            wind: vec![WindZone::uniform(Wind {
+                speed: 10.0,
+                direction: 90.0,
+                vertical: 0.0,
+            })],
+            primary_load: 0,
+            look_angle: 0.0,
+            turret: Turret::default(),
+            chart_value: ChartValue::Drop,
+            chart_angular: false,
+            dope_card: DopeCardSetup::default(),
+        }
+    }
+}
+
+impl BallisticsSetup {
+    /// Short descriptions of what differs from `previous`, for the history
+    /// list: numeric inputs with their new value, anything else by the
+    /// section it was changed in. The primary load is described with the
+    /// loads, since it is an index into them.
+    pub fn changes(&self, previous: &BallisticsSetup, units: UnitSystem) -> Vec<String> {
+        let mut changes = Vec::new();
+        for (input, value, old) in [
+            (
+                SIGHT_HEIGHT,
+                self.rifle.sight_height,
+                previous.rifle.sight_height,
+            ),
+            (ZERO_RANGE, self.rifle.zero_range, previous.rifle.zero_range),
+            (TWIST, self.rifle.twist, previous.rifle.twist),
+            (
+                TABLE_MAX_RANGE,
+                self.table_max_range,
+                previous.table_max_range,
+            ),
+            (TABLE_STEP, self.table_step, previous.table_step),
+            (DOPE_CARD_STEP, self.dope_card.step, previous.dope_card.step),
+        ] {
+            if value != old {
+                changes.push(format!(
+                    "{}: {}",
+                    tr(input.name),
+                    input.quantity.format(units, value)
+                ));
+            }
+        }
+        let sections = [
+            (
+                "Firearm",
+                self.rifle.twist_direction != previous.rifle.twist_direction
+                    || self.turret != previous.turret,
+            ),
+            (
+                "Zero",
+                self.rifle.zero_height != previous.rifle.zero_height
+                    || self.rifle.zero_windage != previous.rifle.zero_windage
+                    || self.zero_atmosphere_enabled != previous.zero_atmosphere_enabled
+                    || self.zero_atmosphere != previous.zero_atmosphere,
+            ),
+            (
+                "Environment",
+                self.atmosphere != previous.atmosphere
+                    || self.use_density_altitude != previous.use_density_altitude
+                    || self.density_altitude != previous.density_altitude
+                    || self.wind != previous.wind,
+            ),
+            ("Look angle", self.look_angle != previous.look_angle),
+            (
+                "Earth rotation",
+                self.earth_rotation_enabled != previous.earth_rotation_enabled
+                    || self.earth_rotation != previous.earth_rotation,
+            ),
+            (
+                "Trajectory comparison",
+                self.chart_value != previous.chart_value
+                    || self.chart_angular != previous.chart_angular,
+            ),
+            ("Dope card", self.dope_card.size != previous.dope_card.size),
+        ];
+        changes.extend(
+            sections
+                .iter()
+                .filter(|(_, changed)| *changed)
+                .map(|(section, _)| tr(section).to_string()),
+        );
+        changes
+    }
+
+    fn validate(&self, units: UnitSystem) -> Vec<Validation> {
+        let mut messages = vec![
+            SIGHT_HEIGHT.validate(self.rifle.sight_height, units),
+            ZERO_RANGE.validate(self.rifle.zero_range, units),
+            TWIST.validate(self.rifle.twist, units),
+            TABLE_MAX_RANGE.validate(self.table_max_range, units),
+            TABLE_STEP.validate(self.table_step, units),
+            DOPE_CARD_STEP.validate(self.dope_card.step, units),
+        ];
+        messages.retain(|message| *message != Validation::Ok);
+        messages
+    }
+
+    pub fn solver(&self) -> Solver {
+        Solver {
+            rifle: self.rifle,
+            atmosphere: self.atmosphere(),
+            wind: self.wind.clone(),
+            earth_rotation: self.earth_rotation_enabled.then_some(self.earth_rotation),
+            look_angle: self.look_angle,
+            zero_atmosphere: self.zero_atmosphere_enabled.then_some(self.zero_atmosphere),
+        }
+    }
+
+    fn atmosphere(&self) -> Atmosphere {
+        if self.use_density_altitude {
+            Atmosphere::from_density_altitude(self.density_altitude, self.atmosphere.temperature)
+        } else {
+            self.atmosphere
+        }
+    }
+
+    /// Columns of the trajectory table, vertical components after the drop
+    /// and horizontal ones before the windage they add up to.
+    fn columns(&self) -> Vec<Column> {
+        let mut columns = vec![Column::Range, Column::Drop];
+        if self.look_angle != 0.0 {
+            columns.push(Column::RiflemansRule);
+        }
+        columns.push(Column::AerodynamicJump);
+        if self.earth_rotation_enabled {
+            columns.push(Column::Eotvos);
+        }
+        columns.extend([Column::WindDrift, Column::SpinDrift]);
+        if self.earth_rotation_enabled {
+            columns.push(Column::Coriolis);
+        }
+        columns.extend([
+            Column::Windage,
+            Column::Elevation,
+            Column::ElevationClicks,
+            Column::WindHold,
+            Column::WindClicks,
+            Column::Velocity,
+            Column::Energy,
+            Column::TimeOfFlight,
+        ]);
+        columns
+    }
+
+    /// Ranges plotted on the trajectory chart, in yards, out to the end of
+    /// the table.
+    fn chart_ranges(&self) -> Vec<f64> {
+        (0..=CHART_POINTS)
+            .map(|point| point as f64 * self.table_max_range / CHART_POINTS as f64)
+            .collect()
+    }
+
+    /// Unit of the chart's holds.
+    fn chart_unit(&self, units: UnitSystem) -> &'static str {
+        if self.chart_angular {
+            self.turret.unit.label()
+        } else {
+            Quantity::Deflection.unit(units)
+        }
+    }
+
+    /// `inches` of drop or drift at `range` in the chart's unit, or `None`
+    /// at the muzzle, where it has no angle.
+    fn chart_hold(&self, inches: f64, range: f64, units: UnitSystem) -> Option<f64> {
+        if !self.chart_angular {
+            Some(Quantity::Deflection.display_value(units, inches))
+        } else if range > 0.0 {
+            Some(self.turret.unit.angle(inches, range))
+        } else {
+            None
+        }
+    }
+
+    /// A hold from `chart_hold`, with its unit.
+    fn format_chart_hold(&self, hold: f64, units: UnitSystem) -> String {
+        let decimals = if self.chart_angular {
+            2
+        } else {
+            Quantity::Deflection.decimals(units)
+        };
+        format!(
+            "{} {}",
+            format_number(hold, decimals),
+            self.chart_unit(units)
+        )
+    }
+
+    /// The chart's point for `point`, in the user's units.
+    fn chart_point(&self, point: &TrajectoryPoint, units: UnitSystem) -> Option<[f64; 2]> {
+        let inches = match self.chart_value {
+            ChartValue::Drop => point.drop,
+            ChartValue::Windage => point.windage(),
+        };
+        let hold = self.chart_hold(inches, point.range, units)?;
+        Some([Quantity::Range.display_value(units, point.range), hold])
+    }
+
+    /// Ranges of the trajectory table rows, in yards, from the muzzle.
+    fn table_ranges(&self) -> Vec<f64> {
+        steps(self.table_max_range, self.table_step)
+    }
+
+    /// Ranges of the dope card rows, in yards, out to the end of the table.
+    fn dope_card_ranges(&self) -> Vec<f64> {
+        steps(self.table_max_range, self.dope_card.step)
+    }
+
+    /// The rifle, zero and conditions, one line each, to head a dope card
+    /// for `load`. `zeroed_for` is the primary load, if that is another one.
+    fn dope_card_header(
+        &self,
+        load: &Load,
+        zeroed_for: Option<&Load>,
+        units: UnitSystem,
+    ) -> Vec<String> {
+        let rifle = &self.rifle;
+        let twist = match rifle.twist_direction {
+            TwistDirection::Right => tr("Right-hand"),
+            TwistDirection::Left => tr("Left-hand"),
+        };
+        let drag = match &load.drag_curve {
+            Some(curve) => format!("CDM: {}", curve.name),
+            None => format!(
+                "{} {}",
+                format_number(load.bc_at(load.muzzle_velocity), 3),
+                load.drag_model.label()
+            ),
+        };
+        let zero_range = Quantity::Range.format(units, rifle.zero_range);
+        let zero = match zeroed_for {
+            Some(primary) => tr_args("{0} for {1}", &[&zero_range, &primary.name]),
+            None => zero_range,
+        };
+        let atmosphere = self.atmosphere();
+        let mut air = vec![Quantity::Temperature.format(units, atmosphere.temperature)];
+        if self.use_density_altitude {
+            air.push(tr_args(
+                "density altitude {0}",
+                &[&Quantity::Altitude.format(units, self.density_altitude)],
+            ));
+        } else {
+            air.push(Quantity::Pressure.format(units, atmosphere.station_pressure()));
+            air.push(tr_args(
+                "{0}% humidity",
+                &[&format_number(atmosphere.humidity, 0)],
+            ));
+        }
+        let wind = match &self.wind[..] {
+            [zone] => tr_args(
+                "{0} from {1}°",
+                &[
+                    &Quantity::WindSpeed.format(units, zone.wind.speed),
+                    &format_number(zone.wind.direction, 0),
+                ],
+            ),
+            zones => tr_args("{0} zones", &[&zones.len().to_string()]),
+        };
+
+        let mut header = vec![
+            format!(
+                "{} {}",
+                tr("Rifle:"),
+                tr_args(
+                    "sight height {0}, twist 1:{1}, {2}",
+                    &[
+                        &Quantity::SightHeight.format(units, rifle.sight_height),
+                        &Quantity::Twist.format(units, rifle.twist),
+                        twist,
+                    ],
+                )
+            ),
+            format!(
+                "{} {}, {}, {}",
+                tr("Load:"),
+                Quantity::BulletWeight.format(units, load.weight),
+                drag,
+                Quantity::Velocity.format(units, load.muzzle_velocity)
+            ),
+            format!("{} {}", tr("Zero:"), zero),
+            format!("{} {}", tr("Air:"), air.join(", ")),
+            format!("{} {}", tr("Wind:"), wind),
+        ];
+        if self.look_angle != 0.0 {
+            header.push(format!(
+                "{}: {}°",
+                tr("Look angle"),
+                format_number(self.look_angle, 0)
+            ));
+        }
+        header
+    }
+}
+
+/// A load being added or edited.
+struct LoadEditor {
+    /// Position of the load being edited, or `None` for a new one.
+    index: Option<usize>,
+    load: Load,
+    opener: FileOpener,
+    import_error: Option<String>,
+}
+
+enum EditorAction {
+    Save,
+    Cancel,
+}
+
+impl LoadEditor {
+    fn new(index: Option<usize>, load: Load) -> Self {
+        Self {
+            index,
+            load,
+            opener: FileOpener::default(),
+            import_error: None,
+        }
+    }
+
+    /// Uses a drag curve file picked by the user. CSV files carry no
+    /// diameter, so unless the load already has one it has to be entered
+    /// before the load can be saved.
+    fn import_curve(&mut self, text: &str) -> Result<(), String> {
+        let file = CurveFile::parse(text)?;
+        if let Some(weight) = file.weight {
+            self.load.weight = weight;
+        }
+        if let Some(diameter) = file.diameter {
+            self.load.diameter = diameter;
+        }
+        self.load.drag_curve = Some(DragCurve {
+            name: file
+                .name
+                .unwrap_or_else(|| tr("Custom drag curve").to_string()),
+            points: file.points,
+        });
+        Ok(())
+    }
+
+    fn ui(&mut self, ctx: &egui::Context, units: UnitSystem) -> Option<EditorAction> {
+        if let Some(result) = self.opener.take() {
+            self.import_error = result.and_then(|text| self.import_curve(&text)).err();
+        }
+        let title = match self.index {
+            Some(_) => tr("Edit Load"),
+            None => tr("Add New Load"),
+        };
+        let mut action = None;
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("load_editor")
+                    .num_columns(3)
+                    .show(ui, |ui| {
+                        let label = ui.label(tr("Name:"));
+                        ui.text_edit_singleline(&mut self.load.name)
+                            .labelled_by(label.id);
+                        ui.end_row();
+
+                        BULLET_WEIGHT.show(ui, &mut self.load.weight, units);
+                        ui.end_row();
+
+                        self.render_drag(ui, units);
+
+                        LOAD_VELOCITY.show(ui, &mut self.load.muzzle_velocity, units);
+                        ui.end_row();
+
+                        self.render_dimensions(ui, units);
+                    });
+
+                if let Some(error) = &self.import_error {
+                    ui.colored_label(ui.visuals().error_fg_color, format!("⛔ {}", error));
+                }
+                let messages = validate(&self.load, units);
+                for message in &messages {
+                    match message {
+                        Validation::Warning(text) => {
+                            ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", text));
+                        }
+                        Validation::Error(text) => {
+                            ui.colored_label(ui.visuals().error_fg_color, format!("⛔ {}", text));
+                        }
+                        Validation::Ok => {}
+                    }
+                }
+
+                ui.horizontal(|ui| {
+                    let valid = !messages.iter().any(Validation::is_error);
+                    if ui
+                        .add_enabled(valid, egui::Button::new(tr("Save")))
+                        .clicked()
+                    {
+                        action = Some(EditorAction::Save);
+                    }
+                    if ui.button(tr("Cancel")).clicked() {
+                        action = Some(EditorAction::Cancel);
+                    }
+                });
+            });
+        action
+    }
+
+    /// Rows for the BC and drag model, or for the custom drag curve that
+    /// replaces them.
+    fn render_drag(&mut self, ui: &mut Ui, units: UnitSystem) {
+        if let Some(curve) = &mut self.load.drag_curve {
+            ui.label(tr("Drag curve:"));
+            let mut remove = false;
+            ui.horizontal(|ui| {
+                ui.label(&curve.name).on_hover_text(tr_args(
+                    "{0} points from Mach {1} to {2}",
+                    &[
+                        &curve.points.len().to_string(),
+                        &format_number(curve.points[0].0, 2),
+                        &format_number(curve.points[curve.points.len() - 1].0, 2),
+                    ],
+                ));
+                remove = ui
+                    .button("✖")
+                    .on_hover_text(tr("Use a BC instead"))
+                    .clicked();
+            });
+            ui.end_row();
+
+            if remove {
+                self.load.drag_curve = None;
+            }
+        } else {
+            let label = ui.label(tr("BC:"));
+            ui.horizontal(|ui| {
+                let response = ui.add(bc_value(&mut self.load.bc)).labelled_by(label.id);
+                if !self.load.bc_bands.is_empty() {
+                    response.on_hover_text(tr("Below the slowest BC band"));
+                }
+                self.render_drag_model(ui);
+            });
+            ui.end_row();
+            self.render_bc_bands(ui, units);
+        }
+
+        ui.label("");
+        if ui
+            .button(tr("Import drag curve…"))
+            .on_hover_text(tr("A CSV or JSON file of Mach and Cd points"))
+            .clicked()
+        {
+            self.opener.open(ui.ctx(), &[FileType::CSV, FileType::JSON]);
+        }
+        ui.end_row();
+    }
+
+    /// The bullet's diameter and length, which spin drift needs. A drag
+    /// curve needs the diameter too.
+    fn render_dimensions(&mut self, ui: &mut Ui, units: UnitSystem) {
+        let hint = tr("Leave at zero if unknown; needed for spin drift");
+        let label = ui.label(tr("Bullet diameter:"));
+        ui.add(quantity_value(
+            &mut self.load.diameter,
+            Quantity::Diameter,
+            units,
+        ))
+        .labelled_by(label.id)
+        .on_hover_text(if self.load.drag_curve.is_some() {
+            tr("Needed for the drag curve and spin drift")
+        } else {
+            hint
+        });
+        ui.end_row();
+
+        let label = ui.label(tr("Bullet length:"));
+        ui.add(quantity_value(
+            &mut self.load.length,
+            Quantity::BulletLength,
+            units,
+        ))
+        .labelled_by(label.id)
+        .on_hover_text(hint);
+        ui.end_row();
+    }
+
+    /// A row per velocity band, each with its own BC, and a button to add
+    /// another.
+    fn render_bc_bands(&mut self, ui: &mut Ui, units: UnitSystem) {
+        let mut remove = None;
+        for (index, band) in self.load.bc_bands.iter_mut().enumerate() {
+            ui.label(tr("BC band:"));
+            ui.horizontal(|ui| {
+                ui.add(bc_value(&mut band.bc));
+                ui.label(tr("from"));
+                ui.add(quantity_value(
+                    &mut band.velocity,
+                    Quantity::Velocity,
+                    units,
+                ))
+                .on_hover_text(tr("The BC applies at and above this velocity"));
+                if ui.button("✖").on_hover_text(tr("Remove band")).clicked() {
+                    remove = Some(index);
+                }
+            });
+            ui.end_row();
+        }
+        if let Some(index) = remove {
+            self.load.bc_bands.remove(index);
+        }
+
+        ui.label("");
+        if ui
+            .button(tr("＋ Add BC band"))
+            .on_hover_text(tr(
+                "Use a different BC above a velocity, as published for some bullets",
+            ))
+            .clicked()
+        {
+            self.load.bc_bands.push(self.next_band());
+        }
+        ui.end_row();
+    }
+
+    /// A band 500 fps below the slowest so far, starting with the BC that
+    /// applies there.
+    fn next_band(&self) -> BcBand {
+        let slowest = self
+            .load
+            .bc_bands
+            .iter()
+            .map(|band| band.velocity)
+            .fold(self.load.muzzle_velocity, f64::min);
+        let velocity = (slowest - 500.0).max(500.0);
+        BcBand {
+            velocity,
+            bc: self.load.bc_at(velocity),
+        }
+    }
+
+    fn render_drag_model(&mut self, ui: &mut Ui) {
+        ComboBox::from_id_salt("drag_model")
+            .selected_text(self.load.drag_model.label())
+            .show_ui(ui, |ui| {
+                for model in DragModel::ALL {
+                    ui.selectable_value(&mut self.load.drag_model, model, model.label())
+                        .on_hover_text(model.description());
+                }
+            })
+            .response
+            .on_hover_text(tr("Drag model the BC is referenced to"));
+    }
+}
+
+/// One line per band, fastest first, for the load list.
+fn bc_bands_summary(load: &Load, units: UnitSystem) -> String {
+    let mut bands = load.bc_bands.clone();
+    bands.sort_by(|a, b| b.velocity.total_cmp(&a.velocity));
+    let mut lines: Vec<String> = bands
+        .iter()
+        .map(|band| {
+            tr_args(
+                "{0} from {1}",
+                &[
+                    &format_number(band.bc, 3),
+                    &Quantity::Velocity.format(units, band.velocity),
+                ],
+            )
+        })
+        .collect();
+    lines.push(tr_args("{0} below", &[&format_number(load.bc, 3)]));
+    lines.join("\n")
+}
+
+/// What the zeros, chart and table were solved for, so they are only
+/// solved again when they change.
+#[derive(Debug, Clone, PartialEq)]
+struct Inputs {
+    solver: Solver,
+    loads: Vec<Load>,
+    primary_load: usize,
+    table_ranges: Vec<f64>,
+    chart_ranges: Vec<f64>,
+}
+
+/// Every load fired with the primary load's zero.
+#[derive(Debug, Clone, PartialEq)]
+struct Solution {
+    /// Where each load crosses the line of sight.
+    crossings: Vec<Crossings>,
+    /// Each load's name and trajectory at the chart's ranges.
+    chart: Vec<(String, Vec<TrajectoryPoint>)>,
+    /// Each load's trajectory at the table's ranges.
+    tables: Vec<Vec<TrajectoryPoint>>,
+}
+
+impl Inputs {
+    fn solve(&self) -> Result<Solution, String> {
+        let solver = &self.solver;
+        let zero = solver.zero(&self.loads[self.primary_load])?;
+        Ok(Solution {
+            crossings: self
+                .loads
+                .iter()
+                .map(|load| solver.crossings(load, zero, 0.0, CROSSING_SEARCH_RANGE))
+                .collect(),
+            chart: self
+                .loads
+                .iter()
+                .map(|load| {
+                    let points = solver.trajectory(load, zero, &self.chart_ranges);
+                    (load.name.clone(), points)
+                })
+                .collect(),
+            tables: self
+                .loads
+                .iter()
+                .map(|load| solver.trajectory(load, zero, &self.table_ranges))
+                .collect(),
+        })
+    }
+}
+
+/// State of the ballistics page.
+pub struct BallisticsApp {
+    setup: BallisticsSetup,
+    loads: Vec<Load>,
+    /// Index of the load shown in the trajectory table.
+    selected_load: usize,
+    editor: Option<LoadEditor>,
+    /// Why the last dope card couldn't be made or saved.
+    dope_card_error: Option<String>,
+    solution: Option<(Inputs, Result<Solution, String>)>,
+}
+
+impl BallisticsApp {
+    pub fn new(setup: BallisticsSetup, loads: Vec<Load>) -> Self {
+        Self {
+            setup,
+            loads,
+            selected_load: 0,
+            editor: None,
+            dope_card_error: None,
+            solution: None,
+        }
+    }
+
+    pub fn setup(&self) -> &BallisticsSetup {
+        &self.setup
+    }
+
+    pub fn loads(&self) -> &[Load] {
+        &self.loads
+    }
+
+    /// Puts back a setup and loads from the undo history. An open editor
+    /// is closed if its load no longer exists.
+    pub fn restore(&mut self, setup: BallisticsSetup, loads: Vec<Load>) {
+        self.setup = setup;
+        self.loads = loads;
+        self.selected_load = self.selected_load.min(self.loads.len().saturating_sub(1));
+        if let Some(LoadEditor {
+            index: Some(index), ..
+        }) = self.editor
+            && index >= self.loads.len()
+        {
+            self.editor = None;
+        }
+    }
+
+    fn render_rifle(&mut self, ui: &mut Ui, units: UnitSystem) {
+        ui.heading(tr("Firearm"));
+        egui::Grid::new("rifle").num_columns(3).show(ui, |ui| {
+            let rifle = &mut self.setup.rifle;
+            SIGHT_HEIGHT.show(ui, &mut rifle.sight_height, units);
+            ui.end_row();
+            TWIST
+                .show(ui, &mut rifle.twist, units)
+                .on_hover_text(tr("Barrel length per turn of the rifling"));
+            ui.end_row();
+            ui.label("");
+            ui.horizontal(|ui| {
+                let direction = &mut rifle.twist_direction;
+                ui.selectable_value(direction, TwistDirection::Right, tr("Right-hand"));
+                ui.selectable_value(direction, TwistDirection::Left, tr("Left-hand"));
+            });
+            ui.end_row();
+
+            let turret = &mut self.setup.turret;
+            let label = ui.label(tr("Turret:"));
+            ui.horizontal(|ui| {
+                for unit in AngleUnit::ALL {
+                    let selected = ui
+                        .selectable_label(turret.unit == unit, unit.label())
+                        .on_hover_text(unit.description());
+                    if selected.clicked() {
+                        turret.set_unit(unit);
+                    }
+                }
+            })
+            .response
+            .labelled_by(label.id);
+            ui.end_row();
+            let label = ui.label(tr("Click:"));
+            ComboBox::from_id_salt("turret_click")
+                .selected_text(turret.unit.click_label(turret.click))
+                .show_ui(ui, |ui| {
+                    for &click in turret.unit.clicks() {
+                        ui.selectable_value(
+                            &mut turret.click,
+                            click,
+                            turret.unit.click_label(click),
+                        );
+                    }
+                })
+                .response
+                .labelled_by(label.id);
+            ui.end_row();
+        });
+
+        self.render_zero(ui, units);
+        self.render_environment(ui, units);
+        self.render_look_angle(ui);
+        self.render_earth_rotation(ui);
+
+        ui.heading(tr("Trajectory table"));
+        egui::Grid::new("table_settings")
+            .num_columns(3)
+            .show(ui, |ui| {
+                TABLE_MAX_RANGE.show(ui, &mut self.setup.table_max_range, units);
+                ui.end_row();
+                TABLE_STEP.show(ui, &mut self.setup.table_step, units);
+                ui.end_row();
+            });
+
+        for message in self.setup.validate(units) {
+            match message {
+                Validation::Warning(text) => {
+                    ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", text));
+                }
+                Validation::Error(text) => {
+                    ui.colored_label(ui.visuals().error_fg_color, format!("⛔ {}", text));
+                }
+                Validation::Ok => {}
+            }
+        }
+    }
+
+    fn render_zero(&mut self, ui: &mut Ui, units: UnitSystem) {
+        ui.heading(tr("Zero"));
+        let setup = &mut self.setup;
+        egui::Grid::new("zero").num_columns(3).show(ui, |ui| {
+            ZERO_RANGE.show(ui, &mut setup.rifle.zero_range, units);
+            ui.end_row();
+
+            let label = ui.label(tr("Impact high:"));
+            ui.add(quantity_value(
+                &mut setup.rifle.zero_height,
+                Quantity::Deflection,
+                units,
+            ))
+            .labelled_by(label.id)
+            .on_hover_text(tr(
+                "Where the bullet hits above the aim at the zero range; negative for low",
+            ));
+            ui.end_row();
+
+            let label = ui.label(tr("Impact right:"));
+            ui.add(quantity_value(
+                &mut setup.rifle.zero_windage,
+                Quantity::Deflection,
+                units,
+            ))
+            .labelled_by(label.id)
+            .on_hover_text(tr(
+                "Where the bullet hits right of the aim at the zero range; negative for left",
+            ));
+            ui.end_row();
+        });
+
+        ui.checkbox(
+            &mut setup.zero_atmosphere_enabled,
+            tr("Zeroed in different conditions"),
+        )
+        .on_hover_text(tr(
+            "The zero shifts with the air, so give the temperature and station pressure on the day it was set",
+        ));
+        if !setup.zero_atmosphere_enabled {
+            return;
+        }
+        let atmosphere = &mut setup.zero_atmosphere;
+        egui::Grid::new("zero_atmosphere")
+            .num_columns(2)
+            .show(ui, |ui| {
+                let label = ui.label(tr("Temperature:"));
+                ui.add(limited_value(
+                    &mut atmosphere.temperature,
+                    Quantity::Temperature,
+                    TEMPERATURE_RANGE,
+                    units,
+                ))
+                .labelled_by(label.id);
+                ui.end_row();
+
+                let label = ui.label(tr("Pressure:"));
+                ui.add(limited_value(
+                    &mut atmosphere.pressure,
+                    Quantity::Pressure,
+                    PRESSURE_RANGE,
+                    units,
+                ))
+                .labelled_by(label.id);
+                ui.end_row();
+            });
+    }
+
+    fn render_environment(&mut self, ui: &mut Ui, units: UnitSystem) {
+        ui.heading(tr("Environment"));
+        let setup = &mut self.setup;
+        egui::Grid::new("environment")
+            .num_columns(2)
+            .show(ui, |ui| {
+                let label = ui.label(tr("Temperature:"));
+                ui.add(limited_value(
+                    &mut setup.atmosphere.temperature,
+                    Quantity::Temperature,
+                    TEMPERATURE_RANGE,
+                    units,
+                ))
+                .labelled_by(label.id);
+                ui.end_row();
+
+                ui.label(tr("Air:"));
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut setup.use_density_altitude, false, tr("Pressure"));
+                    ui.selectable_value(
+                        &mut setup.use_density_altitude,
+                        true,
+                        tr("Density altitude"),
+                    );
+                });
+                ui.end_row();
+
+                if setup.use_density_altitude {
+                    let label = ui.label(tr("Density altitude:"));
+                    ui.add(limited_value(
+                        &mut setup.density_altitude,
+                        Quantity::Altitude,
+                        ALTITUDE_RANGE,
+                        units,
+                    ))
+                    .labelled_by(label.id);
+                    ui.end_row();
+                    return;
+                }
+
+                let atmosphere = &mut setup.atmosphere;
+                let label = ui.label(tr("Pressure:"));
+                ui.horizontal(|ui| {
+                    ui.add(limited_value(
+                        &mut atmosphere.pressure,
+                        Quantity::Pressure,
+                        PRESSURE_RANGE,
+                        units,
+                    ))
+                    .labelled_by(label.id);
+                    let reference = &mut atmosphere.pressure_reference;
+                    ui.selectable_value(reference, PressureReference::Station, tr("Station"))
+                        .on_hover_text(tr(
+                            "Absolute pressure where you are, as a weather meter reads it",
+                        ));
+                    ui.selectable_value(reference, PressureReference::SeaLevel, tr("Sea level"))
+                        .on_hover_text(tr(
+                            "Barometric pressure corrected to sea level, as weather reports give it",
+                        ));
+                });
+                ui.end_row();
+
+                if atmosphere.pressure_reference == PressureReference::SeaLevel {
+                    let label = ui.label(tr("Altitude:"));
+                    ui.add(limited_value(
+                        &mut atmosphere.altitude,
+                        Quantity::Altitude,
+                        ALTITUDE_RANGE,
+                        units,
+                    ))
+                    .labelled_by(label.id);
+                    ui.end_row();
+                }
+
+                let label = ui.label(tr("Humidity:"));
+                ui.add(
+                    DragValue::new(&mut atmosphere.humidity)
+                        .range(0.0..=100.0)
+                        .max_decimals(0)
+                        .custom_formatter(|value, _| format_number(value, 0))
+                        .custom_parser(parse_number)
+                        .suffix(" %"),
+                )
+                .labelled_by(label.id);
+                ui.end_row();
+
+                ui.label("");
+                ui.label(tr_args(
+                    "Density altitude: {0}",
+                    &[&Quantity::Altitude.format(units, atmosphere.density_altitude())],
+                ));
+                ui.end_row();
+            });
+        self.render_wind(ui, units);
+    }
+
+    /// A row per wind zone, each with its start, speed and direction, and a
+    /// button to add another.
+    fn render_wind(&mut self, ui: &mut Ui, units: UnitSystem) {
+        let zones = &mut self.setup.wind;
+        let mut remove = None;
+        let removable = zones.len() > 1;
+        egui::Grid::new("wind").num_columns(2).show(ui, |ui| {
+            for (index, zone) in zones.iter_mut().enumerate() {
+                ui.label(tr("Wind from:"));
+                ui.horizontal(|ui| {
+                    ui.add(limited_value(
+                        &mut zone.start,
+                        Quantity::Range,
+                        0.0..=5000.0,
+                        units,
+                    ))
+                    .on_hover_text(tr("Range this wind starts at"));
+                    if removable && ui.button("✖").on_hover_text(tr("Remove zone")).clicked() {
+                        remove = Some(index);
+                    }
+                });
+                ui.end_row();
+
+                let label = ui.label(tr("Wind:"));
+                ui.horizontal(|ui| {
+                    ui.add(limited_value(
+                        &mut zone.wind.speed,
+                        Quantity::WindSpeed,
+                        WIND_SPEED_RANGE,
+                        units,
+                    ))
+                    .labelled_by(label.id);
+                    ui.add(
+                        DragValue::new(&mut zone.wind.direction)
+                            .range(0.0..=360.0)
+                            .max_decimals(0)
+                            .custom_formatter(|value, _| format_number(value, 0))
+                            .custom_parser(parse_number)
+                            .suffix("°"),
+                    )
+                    .labelled_by(label.id)
+                    .on_hover_text(tr(
+                        "Direction the wind blows from, clockwise from the target",
+                    ));
+                    ui.label(wind_direction_label(zone.wind.direction));
+                });
+                ui.end_row();
+            }
+        });
+        if let Some(index) = remove {
+            zones.remove(index);
+        }
+        if ui.button(tr("＋ Add wind zone")).clicked() {
+            let last = zones
+                .iter()
+                .max_by(|a, b| a.start.total_cmp(&b.start))
+                .copied()
+                .unwrap_or(WindZone::uniform(Wind::default()));
+            zones.push(WindZone {
+                start: last.start + 300.0,
+                wind: last.wind,
+            });
+        }
+    }
+
+    fn render_look_angle(&mut self, ui: &mut Ui) {
+        ui.heading(tr("Look angle"));
+        let look_angle = &mut self.setup.look_angle;
+        egui::Grid::new("look_angle").num_columns(2).show(ui, |ui| {
+            let label = ui.label(tr("Angle:"));
+            ui.add(
+                DragValue::new(look_angle)
+                    .range(-MAX_LOOK_ANGLE..=MAX_LOOK_ANGLE)
+                    .speed(0.5)
+                    .max_decimals(1)
+                    .custom_formatter(|value, _| format_number(value, 1))
+                    .custom_parser(parse_number)
+                    .suffix("°"),
+            )
+            .labelled_by(label.id)
+            .on_hover_text(tr("Positive uphill, negative downhill"));
+            ui.end_row();
+
+            // An angle cosine indicator reads the cosine, which doesn't say
+            // whether the shot is up or down, so the sign is kept
+            let label = ui.label(tr("Cosine:"));
+            let uphill = *look_angle >= 0.0;
+            ui.add(
+                DragValue::from_get_set(|cosine| {
+                    if let Some(cosine) = cosine {
+                        let angle = cosine.acos().to_degrees();
+                        *look_angle = if uphill { angle } else { -angle };
+                    }
+                    look_angle.to_radians().cos()
+                })
+                .range(MAX_LOOK_ANGLE.to_radians().cos()..=1.0)
+                .speed(0.005)
+                .max_decimals(3)
+                .custom_formatter(|value, _| format_number(value, 3))
+                .custom_parser(parse_number),
+            )
+            .labelled_by(label.id)
+            .on_hover_text(tr(
+                "As an angle cosine indicator reads it. Keeps the shot uphill or downhill.",
+            ));
+            ui.end_row();
+        });
+        if *look_angle != 0.0 {
+            ui.label(tr(
+                "The rifleman's rule column holds for the horizontal distance, for comparison.",
+            ));
+        }
+    }
+
+    fn render_earth_rotation(&mut self, ui: &mut Ui) {
+        ui.heading(tr("Earth rotation"));
+        ui.checkbox(
+            &mut self.setup.earth_rotation_enabled,
+            tr("Correct for Coriolis and Eötvös effects"),
+        )
+        .on_hover_text(tr(
+            "The Earth turning under the bullet moves long shots by a few inches",
+        ));
+        if !self.setup.earth_rotation_enabled {
+            return;
+        }
+        let earth = &mut self.setup.earth_rotation;
+        egui::Grid::new("earth_rotation")
+            .num_columns(2)
+            .show(ui, |ui| {
+                let label = ui.label(tr("Latitude:"));
+                ui.add(
+                    DragValue::new(&mut earth.latitude)
+                        .range(-90.0..=90.0)
+                        .speed(0.1)
+                        .max_decimals(1)
+                        .custom_formatter(|value, _| format_number(value, 1))
+                        .custom_parser(parse_number)
+                        .suffix("°"),
+                )
+                .labelled_by(label.id)
+                .on_hover_text(tr("Positive north of the equator, negative south"));
+                ui.end_row();
+
+                let label = ui.label(tr("Azimuth of fire:"));
+                ui.add(
+                    DragValue::new(&mut earth.azimuth)
+                        .range(0.0..=360.0)
+                        .speed(1.0)
+                        .max_decimals(0)
+                        .custom_formatter(|value, _| format_number(value, 0))
+                        .custom_parser(parse_number)
+                        .suffix("°"),
+                )
+                .labelled_by(label.id)
+                .on_hover_text(tr("Direction of the shot, clockwise from true north"));
+                ui.end_row();
+            });
+    }
+
+    /// The primary load's index, kept in bounds in case loads were removed
+    /// since it was saved.
+    fn primary_load(&self) -> usize {
+        self.setup
+            .primary_load
+            .min(self.loads.len().saturating_sub(1))
+    }
+
+    /// Near and far zeros of every load fired with the primary load's zero,
+    /// then the trajectory of the load picked above the table.
+    fn render_table(&mut self, ui: &mut Ui, settings: &Settings) {
+        let units = settings.units;
+        if self.loads.is_empty() {
+            ui.label(tr("Add a load to see its trajectory."));
+            return;
+        }
+        if self.setup.validate(units).iter().any(Validation::is_error) {
+            ui.label(tr("Correct the inputs to see the trajectory."));
+            return;
+        }
+        let solver = self.setup.solver();
+        let inputs = Inputs {
+            solver: solver.clone(),
+            loads: self.loads.clone(),
+            primary_load: self.primary_load(),
+            table_ranges: self.setup.table_ranges(),
+            chart_ranges: self.setup.chart_ranges(),
+        };
+        let solution = match self.solution(inputs).clone() {
+            Ok(solution) => solution,
+            Err(message) => {
+                let primary = &self.loads[self.primary_load()];
+                let text = format!("⛔ {}: {}", primary.name, message);
+                ui.colored_label(ui.visuals().error_fg_color, text);
+                return;
+            }
+        };
+        self.render_zeros(ui, &solution.crossings, units);
+        ui.separator();
+        self.render_chart(ui, solution.chart, settings);
+        ui.separator();
+
+        ui.horizontal_wrapped(|ui| {
+            for (index, load) in self.loads.iter().enumerate() {
+                ui.selectable_value(&mut self.selected_load, index, &load.name);
+            }
+        });
+        self.selected_load = self.selected_load.min(self.loads.len() - 1);
+        let load = &self.loads[self.selected_load];
+        ui.heading(&load.name);
+        if self.selected_load != self.primary_load() {
+            ui.label(tr_args(
+                "Fired with the zero for {0}",
+                &[&self.loads[self.primary_load()].name],
+            ));
+        }
+        let points = &solution.tables[self.selected_load];
+        match solver.stability_factor(load) {
+            Some(stability) => {
+                let text = tr_args(
+                    "Stability factor (Sg): {0}",
+                    &[&format_number(stability, 2)],
+                );
+                if stability < FULL_STABILITY {
+                    ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", text))
+                        .on_hover_text(tr(
+                            "Below 1.5 the bullet is not fully stable and loses BC; below 1 it tumbles.",
+                        ));
+                } else {
+                    ui.label(text);
+                }
+            }
+            None => {
+                ui.label(tr(
+                    "Enter the bullet's diameter and length and the barrel twist to include spin drift.",
+                ));
+            }
+        }
+
+        let columns = self.setup.columns();
+        let turret = self.setup.turret;
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("trajectory")
+                .num_columns(columns.len())
+                .striped(true)
+                .show(ui, |ui| {
+                    for column in &columns {
+                        ui.label(RichText::new(column.header(units, turret)).strong());
+                    }
+                    ui.end_row();
+                    for point in points {
+                        for column in &columns {
+                            ui.label(column.cell(point, units, turret));
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+
+    /// The zeros, chart and table for the current inputs, solving again
+    /// only if they have changed.
+    fn solution(&mut self, inputs: Inputs) -> &Result<Solution, String> {
+        if self.solution.as_ref().map(|(solved, _)| solved) != Some(&inputs) {
+            let solution = inputs.solve();
+            self.solution = Some((inputs, solution));
+        }
+        &self.solution.as_ref().expect("solved above").1
+    }
+
+    /// Drop or windage of every load against range, on the primary load's
+    /// zero, with a readout of each at the range under the cursor.
+    fn render_chart(
+        &mut self,
+        ui: &mut Ui,
+        trajectories: Vec<(String, Vec<TrajectoryPoint>)>,
+        settings: &Settings,
+    ) {
+        let units = settings.units;
+        let dark_mode = ui.visuals().dark_mode;
+        ui.horizontal_wrapped(|ui| {
+            ui.heading(tr("Trajectory comparison"));
+            ui.separator();
+            for value in [ChartValue::Drop, ChartValue::Windage] {
+                ui.selectable_value(&mut self.setup.chart_value, value, value.label());
+            }
+            ui.separator();
+            let linear = Quantity::Deflection.unit(units);
+            let angular = &mut self.setup.chart_angular;
+            ui.selectable_value(angular, false, linear);
+            ui.selectable_value(angular, true, self.setup.turret.unit.label());
+        });
+
+        let setup = self.setup.clone();
+        let y_label = match setup.chart_value {
+            ChartValue::Drop => "Drop ({0})",
+            ChartValue::Windage => "Windage ({0})",
+        };
+        let readout_trajectories = trajectories.clone();
+        let readout_setup = setup.clone();
+        Plot::new("trajectory_chart")
+            .height(CHART_HEIGHT)
+            .legend(Legend::default())
+            .x_axis_label(tr_args("Range ({0})", &[Quantity::Range.unit(units)]))
+            .y_axis_label(tr_args(y_label, &[setup.chart_unit(units)]))
+            .x_axis_formatter(|mark, _range| format_tick(mark.value, mark.step_size))
+            .y_axis_formatter(|mark, _range| format_tick(mark.value, mark.step_size))
+            .label_formatter(move |_name, value| {
+                let range = Quantity::Range.stored_value(units, value.x);
+                chart_readout(&readout_setup, &readout_trajectories, range, units)
+            })
+            .show(ui, |plot_ui| {
+                let hover_range = plot_ui
+                    .pointer_coordinate()
+                    .map(|pointer| Quantity::Range.stored_value(units, pointer.x));
+                for (index, (name, points)) in trajectories.iter().enumerate() {
+                    let color = settings.palette.load_color(index, dark_mode);
+                    let line: Vec<[f64; 2]> = points
+                        .iter()
+                        .filter_map(|point| setup.chart_point(point, units))
+                        .collect();
+                    plot_ui.line(
+                        Line::new(name.as_str(), PlotPoints::new(line))
+                            .color(color)
+                            .width(2.0),
+                    );
+                    let marker = hover_range
+                        .and_then(|range| nearest(points, range))
+                        .and_then(|point| setup.chart_point(point, units));
+                    if let Some(marker) = marker {
+                        plot_ui.points(
+                            Points::new("", vec![marker])
+                                .color(color)
+                                .radius(4.0)
+                                .shape(MarkerShape::Circle)
+                                .filled(true),
+                        );
+                    }
+                }
+            });
+    }
+
+    /// Where each load crosses the line of sight. For the primary load these
+    /// are its own zeros; for the others, the equivalent zeros on its zero.
+    fn render_zeros(&self, ui: &mut Ui, crossings: &[Crossings], units: UnitSystem) {
+        ui.heading(tr("Zeros"));
+        let range = |range| Quantity::Range.format(units, range);
+        egui::Grid::new("zeros")
+            .num_columns(3)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("");
+                ui.label(RichText::new(tr("Near zero")).strong());
+                ui.label(RichText::new(tr("Far zero")).strong());
+                ui.end_row();
+                for (index, (load, crossings)) in self.loads.iter().zip(crossings).enumerate() {
+                    if index == self.primary_load() {
+                        ui.label(format!("★ {}", load.name))
+                            .on_hover_text(tr("Primary load"));
+                    } else {
+                        ui.label(&load.name);
+                    }
+                    match (crossings.near, crossings.far) {
+                        (Some(near), far) => {
+                            ui.label(range(near));
+                            match far {
+                                Some(far) => ui.label(range(far)),
+                                None => ui.label(tr_args(
+                                    "beyond {0}",
+                                    &[&range(CROSSING_SEARCH_RANGE)],
+                                )),
+                            };
+                        }
+                        // Starting above the line of sight, with a large
+                        // negative zero offset
+                        (None, Some(far)) => {
+                            ui.label("–");
+                            ui.label(range(far));
+                        }
+                        (None, None) => {
+                            ui.colored_label(
+                                ui.visuals().warn_fg_color,
+                                format!("⚠ {}", tr("No crossing")),
+                            )
+                            .on_hover_text(tr(
+                                "This load never rises to the line of sight on the primary load's zero.",
+                            ));
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn render_loads(&mut self, ui: &mut Ui, units: UnitSystem) {
+        ui.horizontal(|ui| {
+            ui.heading(tr("Loads"));
+            if ui.button(tr("＋ Add Load")).clicked() {
+                self.editor = Some(LoadEditor::new(None, new_load()));
+            }
+        });
+
+        let mut remove = None;
+        let primary = self.primary_load();
+        egui::Grid::new("loads")
+            .num_columns(6)
+            .striped(true)
+            .show(ui, |ui| {
+                for (index, load) in self.loads.iter().enumerate() {
+                    let star = if index == primary { "★" } else { "☆" };
+                    if ui
+                        .selectable_label(index == primary, star)
+                        .on_hover_text(tr(
+                            "Primary load: the rifle is zeroed for it, and the others are fired with its zero",
+                        ))
+                        .clicked()
+                    {
+                        self.setup.primary_load = index;
+                    }
+                    ui.label(RichText::new(&load.name).strong());
+                    ui.label(Quantity::BulletWeight.format(units, load.weight));
+                    match &load.drag_curve {
+                        Some(curve) => ui
+                            .label(format!("CDM: {}", curve.name))
+                            .on_hover_text(tr("Custom drag curve")),
+                        None if load.bc_bands.is_empty() => ui
+                            .label(format!(
+                                "{} {}",
+                                format_number(load.bc, 3),
+                                load.drag_model.label()
+                            ))
+                            .on_hover_text(load.drag_model.description()),
+                        None => ui
+                            .label(tr_args(
+                                "{0} {1}, {2} bands",
+                                &[
+                                    &format_number(load.bc_at(load.muzzle_velocity), 3),
+                                    load.drag_model.label(),
+                                    &(load.bc_bands.len() + 1).to_string(),
+                                ],
+                            ))
+                            .on_hover_text(bc_bands_summary(load, units)),
+                    };
+                    ui.label(Quantity::Velocity.format(units, load.muzzle_velocity));
+                    ui.horizontal(|ui| {
+                        if ui.button("✏").on_hover_text(tr("Edit")).clicked() {
+                            self.editor = Some(LoadEditor::new(Some(index), load.clone()));
+                        }
+                        if ui.button("🗑").on_hover_text(tr("Delete")).clicked() {
+                            remove = Some(index);
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+        if let Some(index) = remove {
+            self.loads.remove(index);
+            self.setup.primary_load = index_after_removal(primary, index);
+            self.selected_load = index_after_removal(self.selected_load, index);
+            self.editor = None;
+        }
+    }
+
+    /// A dope card for the load shown in the trajectory table.
+    fn dope_card(&self, units: UnitSystem) -> Result<DopeCard, String> {
+        let solver = self.setup.solver();
+        let primary = &self.loads[self.primary_load()];
+        let index = self.selected_load.min(self.loads.len() - 1);
+        let load = &self.loads[index];
+        let zeroed_for = (index != self.primary_load()).then_some(primary);
+        let zero = solver
+            .zero(primary)
+            .map_err(|message| format!("{}: {}", primary.name, message))?;
+        let points = solver.trajectory(load, zero, &self.setup.dope_card_ranges());
+        Ok(DopeCard::new(
+            load.name.clone(),
+            self.setup.dope_card_header(load, zeroed_for, units),
+            &points,
+            self.setup.dope_card,
+            self.setup.turret,
+            units,
+        ))
+    }
+
+    fn render_dope_card(&mut self, ui: &mut Ui, units: UnitSystem) {
+        ui.heading(tr("Dope card"));
+        let card = &mut self.setup.dope_card;
+        egui::Grid::new("dope_card").num_columns(3).show(ui, |ui| {
+            DOPE_CARD_STEP.show(ui, &mut card.step, units);
+            ui.end_row();
+
+            let label = ui.label(tr("Card:"));
+            ComboBox::from_id_salt("dope_card_size")
+                .selected_text(card.size.label())
+                .show_ui(ui, |ui| {
+                    for size in CardSize::ALL {
+                        ui.selectable_value(&mut card.size, size, size.label());
+                    }
+                })
+                .response
+                .labelled_by(label.id);
+            ui.end_row();
+        });
+
+        let enabled =
+            !self.loads.is_empty() && !self.setup.validate(units).iter().any(Validation::is_error);
+        ui.horizontal(|ui| {
+            let pdf = ui
+                .add_enabled(enabled, egui::Button::new("📄 PDF"))
+                .on_hover_text(tr("Save a printable dope card for the load in the table."));
+            let csv = ui
+                .add_enabled(enabled, egui::Button::new("CSV"))
+                .on_hover_text(tr("Save the dope card as a spreadsheet."));
+            let (bytes, file_name, file_type) = if pdf.clicked() {
+                match self.dope_card(units) {
+                    Ok(card) => (card.to_pdf().to_bytes(), "dope-card.pdf", FileType::PDF),
+                    Err(message) => {
+                        self.dope_card_error = Some(message);
+                        return;
+                    }
+                }
+            } else if csv.clicked() {
+                match self.dope_card(units) {
+                    Ok(card) => (card.to_csv().into_bytes(), "dope-card.csv", FileType::CSV),
+                    Err(message) => {
+                        self.dope_card_error = Some(message);
+                        return;
+                    }
+                }
+            } else {
+                return;
+            };
+            self.dope_card_error = files::save(file_name, &bytes, file_type).err();
+        });
+        if let Some(error) = &self.dope_card_error {
+            ui.colored_label(ui.visuals().error_fg_color, format!("⛔ {}", error));
+        }
+    }
+
+    fn render_editor(&mut self, ctx: &egui::Context, units: UnitSystem) {
+        let Some(editor) = &mut self.editor else {
+            return;
+        };
+        match editor.ui(ctx, units) {
+            Some(EditorAction::Save) => {
+                let mut load = editor.load.clone();
+                load.name = load.name.trim().to_string();
+                if load.drag_curve.is_some() {
+                    load.bc_bands.clear();
+                }
+                load.bc_bands
+                    .sort_by(|a, b| b.velocity.total_cmp(&a.velocity));
+                match editor.index {
+                    Some(index) => self.loads[index] = load,
+                    None => self.loads.push(load),
+                }
+                self.editor = None;
+            }
+            Some(EditorAction::Cancel) => self.editor = None,
+            None => {}
+        }
+    }
+
+    pub fn ui(&mut self, ctx: &egui::Context, settings: &Settings) {
+        let units = settings.units;
+        egui::SidePanel::left("ballistics_config").show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                self.render_rifle(ui, units);
+                ui.separator();
+                self.render_loads(ui, units);
+                ui.separator();
+                self.render_dope_card(ui, units);
+            });
+        });
+        egui::CentralPanel::default().show(ctx, |ui| {
+            self.render_table(ui, settings);
+        });
+        self.render_editor(ctx, units);
+    }
+}
+
+/// Ranges from the muzzle out to `max_range` yards, `step` apart.
+fn steps(max_range: f64, step: f64) -> Vec<f64> {
+    let rows = ((max_range / step).floor() as usize).min(MAX_TABLE_ROWS);
+    (0..=rows).map(|row| row as f64 * step).collect()
+}
+
+/// The point of `points` closest to `range` yards.
+fn nearest(points: &[TrajectoryPoint], range: f64) -> Option<&TrajectoryPoint> {
+    points
+        .iter()
+        .min_by(|a, b| (a.range - range).abs().total_cmp(&(b.range - range).abs()))
+}
+
+/// Each load's drop, drift, velocity, energy and time of flight at the
+/// charted range closest to `range` yards, one line per load.
+fn chart_readout(
+    setup: &BallisticsSetup,
+    trajectories: &[(String, Vec<TrajectoryPoint>)],
+    range: f64,
+    units: UnitSystem,
+) -> String {
+    let mut lines = Vec::new();
+    for (name, points) in trajectories {
+        let Some(point) = nearest(points, range) else {
+            continue;
+        };
+        if lines.is_empty() {
+            lines.push(Quantity::Range.format(units, point.range));
+        }
+        let hold = |inches| match setup.chart_hold(inches, point.range, units) {
+            Some(hold) => setup.format_chart_hold(hold, units),
+            None => "–".to_string(),
+        };
+        lines.push(tr_args(
+            "{0}: drop {1}, drift {2}, {3}, {4}, {5} s",
+            &[
+                name,
+                &hold(point.drop),
+                &hold(point.windage()),
+                &Quantity::Velocity.format(units, point.velocity),
+                &Quantity::Energy.format(units, point.energy),
+                &format_number(point.time_of_flight, 3),
+            ],
+        ));
+    }
+    lines.join("\n")
+}
+
+/// `value` to `decimals` places with its sign, or none if it rounds to
+/// nothing.
+fn signed(value: f64, decimals: usize) -> String {
+    let text = format_number(value.abs(), decimals);
+    if text.trim_start_matches(['0', '.', ',']).is_empty() {
+        text
+    } else if value < 0.0 {
+        format!("-{text}")
+    } else {
+        format!("+{text}")
+    }
+}
+
+/// Where the load at `index` ends up once the load at `removed` is deleted.
+/// Deleting the load itself moves to the one before it, as in the Elm app.
+fn index_after_removal(index: usize, removed: usize) -> usize {
+    if index >= removed && index > 0 {
+        index - 1
+    } else {
+        index
+    }
+}
+
+/// A drag value for a stored value limited to `range`, in the user's units.
+fn limited_value(
+    value: &mut f64,
+    quantity: Quantity,
+    range: std::ops::RangeInclusive<f64>,
+    units: UnitSystem,
+) -> DragValue<'_> {
+    let range =
+        quantity.display_value(units, *range.start())..=quantity.display_value(units, *range.end());
+    quantity_value(value, quantity, units).range(range)
+}
+
+/// Where the wind blows from, as in the Elm app's wind direction label.
+fn wind_direction_label(degrees: f64) -> &'static str {
+    let sector = ((degrees.rem_euclid(360.0) + 22.5) / 45.0).floor() as usize % 8;
+    tr([
+        "Headwind", "R Front", "R Cross", "R Rear", "Tailwind", "L Rear", "L Cross", "L Front",
+    ][sector])
+}
+
+/// A column of the trajectory table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Range,
+    Drop,
+    RiflemansRule,
+    AerodynamicJump,
+    WindDrift,
+    SpinDrift,
+    Coriolis,
+    Eotvos,
+    Windage,
+    /// Elevation to dial, in the turret's unit.
+    Elevation,
+    ElevationClicks,
+    /// Windage to dial, in the turret's unit.
+    WindHold,
+    WindClicks,
+    Velocity,
+    Energy,
+    TimeOfFlight,
+}
+
+impl Column {
+    fn header(self, units: UnitSystem, turret: Turret) -> String {
+        let (template, quantity) = match self {
+            Column::Range => ("Range ({0})", Quantity::Range),
+            Column::Drop => ("Drop ({0})", Quantity::Deflection),
+            Column::RiflemansRule => ("Rifleman's rule ({0})", Quantity::Deflection),
+            Column::AerodynamicJump => ("Aero jump ({0})", Quantity::Deflection),
+            Column::WindDrift => ("Wind drift ({0})", Quantity::Deflection),
+            Column::SpinDrift => ("Spin drift ({0})", Quantity::Deflection),
+            Column::Coriolis => ("Coriolis ({0})", Quantity::Deflection),
+            Column::Eotvos => ("Eötvös ({0})", Quantity::Deflection),
+            Column::Windage => ("Windage ({0})", Quantity::Deflection),
+            Column::Elevation => return tr_args("Elevation ({0})", &[turret.unit.label()]),
+            Column::WindHold => return tr_args("Wind ({0})", &[turret.unit.label()]),
+            Column::ElevationClicks | Column::WindClicks => return tr("Clicks").to_string(),
+            Column::Velocity => ("Velocity ({0})", Quantity::Velocity),
+            Column::Energy => ("Energy ({0})", Quantity::Energy),
+            Column::TimeOfFlight => return tr("TOF (s)").to_string(),
+        };
+        tr_args(template, &[quantity.unit(units)])
+    }
+
+    /// The column's value at `point`, in the user's units. Clicks are
+    /// followed by what they leave out; the muzzle has no adjustments.
+    fn cell(self, point: &TrajectoryPoint, units: UnitSystem, turret: Turret) -> String {
+        let adjustment =
+            |inches| (point.range > 0.0).then(|| turret.adjustment(inches, point.range));
+        let (quantity, value) = match self {
+            Column::Range => (Quantity::Range, point.range),
+            Column::Drop => (Quantity::Deflection, point.drop),
+            Column::RiflemansRule => (Quantity::Deflection, point.riflemans_rule),
+            Column::AerodynamicJump => (Quantity::Deflection, point.aerodynamic_jump),
+            Column::WindDrift => (Quantity::Deflection, point.drift),
+            Column::SpinDrift => (Quantity::Deflection, point.spin_drift),
+            Column::Coriolis => (Quantity::Deflection, point.coriolis),
+            Column::Eotvos => (Quantity::Deflection, point.eotvos),
+            Column::Windage => (Quantity::Deflection, point.windage()),
+            Column::Elevation | Column::ElevationClicks | Column::WindHold | Column::WindClicks => {
+                let inches = match self {
+                    Column::Elevation | Column::ElevationClicks => point.drop,
+                    _ => point.windage(),
+                };
+                return match (self, adjustment(inches)) {
+                    (_, None) => "–".to_string(),
+                    (Column::Elevation | Column::WindHold, Some(adjustment)) => {
+                        format_number(adjustment.angle, 2)
+                    }
+                    (_, Some(adjustment)) => {
+                        format!("{} ({})", adjustment.clicks, signed(adjustment.residual, 3))
+                    }
+                };
+            }
+            Column::Velocity => (Quantity::Velocity, point.velocity),
+            Column::Energy => (Quantity::Energy, point.energy),
+            Column::TimeOfFlight => return format_number(point.time_of_flight, 3),
+        };
+        format_number(
+            quantity.display_value(units, value),
+            quantity.decimals(units),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_loads_are_valid() {
+        for load in default_loads() {
+            assert!(validate(&load, UnitSystem::Imperial).is_empty(), "{load:?}");
+        }
+        assert!(validate(&new_load(), UnitSystem::Imperial).is_empty());
+    }
+
+    #[test]
+    fn test_imported_csv_curve_needs_a_diameter() {
+        let mut editor = LoadEditor::new(None, new_load());
+        editor.import_curve("Mach,Cd\n0.5,0.25\n1.0,0.4\n").unwrap();
+        let curve = editor.load.drag_curve.as_ref().unwrap();
+        assert_eq!(curve.points.len(), 2);
+        assert!(matches!(
+            validate(&editor.load, UnitSystem::Imperial)[..],
+            [Validation::Error(_)]
+        ));
+
+        editor.load.diameter = 0.308;
+        editor.load.length = 1.2;
+        assert!(validate(&editor.load, UnitSystem::Imperial).is_empty());
+
+        // The load's diameter is kept, unless a JSON file gives another
+        editor.import_curve("0.6,0.25\n1.1,0.4\n").unwrap();
+        assert_eq!(editor.load.diameter, 0.308);
+        editor
+            .import_curve(
+                r#"{"diameter": 0.264, "weight": 140, "points": [[0.5, 0.25], [1.0, 0.4]]}"#,
+            )
+            .unwrap();
+        assert_eq!(editor.load.weight, 140.0);
+        assert_eq!(editor.load.diameter, 0.264);
+        assert!(editor.import_curve("0.5,0.25\n").is_err());
+    }
+
+    #[test]
+    fn test_load_validation() {
+        let mut load = new_load();
+        load.name = "  ".to_string();
+        load.bc = 0.0;
+        let messages = validate(&load, UnitSystem::Imperial);
+        assert_eq!(messages.len(), 2);
+        assert!(messages.iter().all(Validation::is_error));
+
+        load.name = "Heavy".to_string();
+        load.bc = 1.2;
+        assert!(matches!(
+            validate(&load, UnitSystem::Imperial)[..],
+            [Validation::Warning(_)]
+        ));
+    }
+
+    #[test]
+    fn test_bc_bands() {
+        let mut editor = LoadEditor::new(None, new_load());
+        let first = editor.next_band();
+        assert_eq!(first.velocity, 2300.0);
+        assert_eq!(first.bc, 0.4);
+        editor.load.bc_bands.push(first);
+        assert_eq!(editor.next_band().velocity, 1800.0);
+        assert!(validate(&editor.load, UnitSystem::Imperial).is_empty());
+
+        editor.load.bc_bands.push(first);
+        let messages = validate(&editor.load, UnitSystem::Imperial);
+        assert_eq!(
+            messages,
+            vec![Validation::Error(
+                "Two BC bands start at 2300 fps.".to_string()
+            )]
+        );
+        editor.load.bc_bands[1] = BcBand {
+            velocity: 0.0,
+            bc: 0.0,
+        };
+        let messages = validate(&editor.load, UnitSystem::Imperial);
+        assert_eq!(messages.len(), 2);
+        assert!(messages.iter().all(Validation::is_error));
+    }
+
+    #[test]
+    fn test_setup() {
+        let mut setup = BallisticsSetup::default();
+        assert!(setup.validate(UnitSystem::Imperial).is_empty());
+        assert_eq!(setup.table_ranges().len(), 11);
+        setup.table_step = 300.0;
+        assert_eq!(setup.table_ranges(), vec![0.0, 300.0, 600.0, 900.0]);
+        setup.rifle.twist = 0.0;
+        assert!(setup.validate(UnitSystem::Imperial)[0].is_error());
+
+        assert!(!setup.columns().contains(&Column::Coriolis));
+        assert_eq!(setup.solver().earth_rotation, None);
+        setup.earth_rotation_enabled = true;
+        assert!(setup.columns().contains(&Column::Eotvos));
+        assert!(setup.solver().earth_rotation.is_some());
+
+        assert!(!setup.columns().contains(&Column::RiflemansRule));
+        setup.look_angle = -20.0;
+        assert!(setup.columns().contains(&Column::RiflemansRule));
+        assert_eq!(setup.solver().look_angle, -20.0);
+
+        assert_eq!(setup.solver().zero_atmosphere, None);
+        setup.zero_atmosphere_enabled = true;
+        assert_eq!(setup.solver().zero_atmosphere, Some(setup.zero_atmosphere));
+
+        setup.use_density_altitude = true;
+        setup.density_altitude = 5000.0;
+        let density_altitude = setup.solver().atmosphere.density_altitude();
+        assert!((density_altitude - 5000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_chart() {
+        let mut setup = BallisticsSetup::default();
+        let ranges = setup.chart_ranges();
+        assert_eq!(ranges.len(), CHART_POINTS + 1);
+        assert_eq!(ranges.last(), Some(&1000.0));
+
+        let point = TrajectoryPoint {
+            range: 500.0,
+            drop: -52.35,
+            drift: 10.47,
+            ..TrajectoryPoint::default()
+        };
+        let muzzle = TrajectoryPoint::default();
+        assert_eq!(
+            setup.chart_point(&point, UnitSystem::Imperial),
+            Some([500.0, -52.35])
+        );
+        assert!(setup.chart_point(&muzzle, UnitSystem::Imperial).is_some());
+
+        // 52.35" at 500 yd is 10 MOA, 10.47 IPHY or 2.91 mil, whatever the
+        // units; the muzzle has no angle
+        setup.chart_angular = true;
+        let [_, moa] = setup.chart_point(&point, UnitSystem::Imperial).unwrap();
+        assert!((moa + 10.0).abs() < 0.01, "{moa}");
+        setup.turret.set_unit(AngleUnit::ShootersMoa);
+        let [_, iphy] = setup.chart_point(&point, UnitSystem::Metric).unwrap();
+        assert!((iphy + 10.47).abs() < 0.01, "{iphy}");
+        setup.turret.set_unit(AngleUnit::Mil);
+        let [meters, mils] = setup.chart_point(&point, UnitSystem::Metric).unwrap();
+        assert!((meters - 457.2).abs() < 1e-9);
+        assert!((mils + 2.909).abs() < 0.001, "{mils}");
+        setup.turret.set_unit(AngleUnit::TrueMoa);
+        assert_eq!(setup.chart_point(&muzzle, UnitSystem::Imperial), None);
+
+        setup.chart_value = ChartValue::Windage;
+        let [_, moa] = setup.chart_point(&point, UnitSystem::Imperial).unwrap();
+        assert!((moa - 2.0).abs() < 0.01, "{moa}");
+
+        let trajectories = vec![("Match".to_string(), vec![muzzle, point])];
+        assert_eq!(
+            chart_readout(&setup, &trajectories, 480.0, UnitSystem::Imperial),
+            "500 yd\nMatch: drop -10.00 MOA, drift 2.00 MOA, 0 fps, 0 ft·lbf, 0.000 s"
+        );
+    }
+
+    #[test]
+    fn test_solution_is_cached() {
+        let setup = BallisticsSetup::default();
+        let loads = default_loads();
+        let mut app = BallisticsApp::new(setup.clone(), loads.clone());
+        let inputs = Inputs {
+            solver: setup.solver(),
+            loads: loads.clone(),
+            primary_load: 0,
+            table_ranges: setup.table_ranges(),
+            chart_ranges: setup.chart_ranges(),
+        };
+        let first = app.solution(inputs.clone()).clone().unwrap();
+        assert_eq!(first.tables.len(), loads.len());
+        assert_eq!(first.tables[0].len(), setup.table_ranges().len());
+        assert_eq!(app.solution(inputs.clone()), &Ok(first.clone()));
+
+        // On the second load's zero the first crosses the line elsewhere
+        let rezeroed = app
+            .solution(Inputs {
+                primary_load: 1,
+                ..inputs
+            })
+            .clone()
+            .unwrap();
+        assert_ne!(rezeroed.crossings[0], first.crossings[0]);
+    }
+
+    #[test]
+    fn test_turret_columns() {
+        let point = TrajectoryPoint {
+            range: 500.0,
+            drop: -52.35,
+            drift: 10.0,
+            ..TrajectoryPoint::default()
+        };
+        let turret = Turret::default();
+        let cell = |column: Column, point| column.cell(point, UnitSystem::Imperial, turret);
+        assert_eq!(cell(Column::Elevation, &point), "10.00");
+        assert_eq!(cell(Column::ElevationClicks, &point), "40 (-0.002)");
+        // 10" right at 500 yd is 1.91 MOA: 8 clicks left, 0.09 short
+        assert_eq!(cell(Column::WindHold, &point), "-1.91");
+        assert_eq!(cell(Column::WindClicks, &point), "-8 (+0.090)");
+        let muzzle = TrajectoryPoint::default();
+        assert_eq!(cell(Column::ElevationClicks, &muzzle), "–");
+        assert_eq!(
+            Column::WindHold.header(UnitSystem::Metric, Turret::default()),
+            "Wind (MOA)"
+        );
+
+        assert_eq!(signed(-0.0004, 3), "0.000");
+        assert_eq!(signed(-0.02, 2), "-0.02");
+    }
+
+    #[test]
+    fn test_dope_card() {
+        let mut setup = BallisticsSetup::default();
+        assert_eq!(setup.dope_card_ranges().len(), 21);
+        setup.dope_card.step = 0.0;
+        assert!(setup.validate(UnitSystem::Imperial)[0].is_error());
+
+        let loads = default_loads();
+        let header = setup.dope_card_header(&loads[1], Some(&loads[0]), UnitSystem::Imperial);
+        assert_eq!(
+            header,
+            [
+                "Rifle: sight height 1.9 in, twist 1:10.0 in, Right-hand".to_string(),
+                format!(
+                    "Load: {}, {} {}, {}",
+                    Quantity::BulletWeight.format(UnitSystem::Imperial, loads[1].weight),
+                    format_number(loads[1].bc, 3),
+                    loads[1].drag_model.label(),
+                    Quantity::Velocity.format(UnitSystem::Imperial, loads[1].muzzle_velocity),
+                ),
+                format!("Zero: 100 yd for {}", loads[0].name),
+                "Air: 60 °F, 29.92 inHg, 0% humidity".to_string(),
+                "Wind: 10.0 mph from 90°".to_string(),
+            ]
+        );
+        setup.use_density_altitude = true;
+        setup.look_angle = 10.0;
+        let header = setup.dope_card_header(&loads[0], None, UnitSystem::Imperial);
+        assert_eq!(header[2], "Zero: 100 yd");
+        assert_eq!(header[3], "Air: 60 °F, density altitude 0 ft");
+        assert_eq!(header[5], "Look angle: 10°");
+    }
+
+    #[test]
+    fn test_index_after_removal() {
+        // Loads 0 to 3 with the primary at 2
+        assert_eq!(index_after_removal(2, 0), 1);
+        assert_eq!(index_after_removal(2, 2), 1);
+        assert_eq!(index_after_removal(2, 3), 2);
+        assert_eq!(index_after_removal(0, 0), 0);
+    }
+
+    #[test]
+    fn test_wind_direction_label() {
+        assert_eq!(wind_direction_label(0.0), "Headwind");
+        assert_eq!(wind_direction_label(90.0), "R Cross");
+        assert_eq!(wind_direction_label(200.0), "Tailwind");
+        assert_eq!(wind_direction_label(350.0), "Headwind");
+        assert_eq!(wind_direction_label(270.0), "L Cross");
+    }
+
+    #[test]
+    fn test_bullet_dimensions() {
+        let mut load = new_load();
+        load.diameter = 0.308;
+        assert!(matches!(
+            validate(&load, UnitSystem::Imperial)[..],
+            [Validation::Warning(_)]
+        ));
+        load.length = 1.215;
+        assert!(validate(&load, UnitSystem::Imperial).is_empty());
+        load.length = -1.0;
+        assert!(validate(&load, UnitSystem::Imperial)[0].is_error());
+    }
+}
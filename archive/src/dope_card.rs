@@ -0,0 +1,411 @@
+//! Dope cards: the trajectory boiled down to the holds to dial or hold at
+//! each range, for taping to a stock or slipping into a wrist coach.
+
+use egui::Color32;
+use serde::{Deserialize, Serialize};
+
+use crate::ballistics::{Adjustment, TrajectoryPoint, Turret};
+use crate::i18n::{format_number, tr, tr_args};
+use crate::pdf::{self, Align, Document, Font, Page};
+use crate::units::{Quantity, UnitSystem};
+
+/// Printed size of the card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CardSize {
+    /// A 5 × 3 in index card, landscape.
+    #[default]
+    IndexCard,
+    /// A 4 × 2.5 in wrist coach insert, landscape.
+    WristCoach,
+}
+
+impl CardSize {
+    pub const ALL: [CardSize; 2] = [CardSize::IndexCard, CardSize::WristCoach];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CardSize::IndexCard => tr("Index card (5 × 3 in)"),
+            CardSize::WristCoach => tr("Wrist coach (4 × 2.5 in)"),
+        }
+    }
+
+    /// Page size in points, font size and margin.
+    fn layout(&self) -> ((f32, f32), f32, f32) {
+        match self {
+            CardSize::IndexCard => ((360.0, 216.0), 7.0, 14.0),
+            CardSize::WristCoach => ((288.0, 180.0), 6.0, 9.0),
+        }
+    }
+}
+
+/// How the dope card is laid out, saved with the ballistics setup.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DopeCardSetup {
+    /// Range between rows, in yards.
+    pub step: f64,
+    pub size: CardSize,
+}
+
+impl Default for DopeCardSetup {
+    fn default() -> Self {
+        Self {
+            step: 50.0,
+            size: CardSize::IndexCard,
+        }
+    }
+}
+
+/// One range on the card.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DopeRow {
+    /// Range, in yards.
+    pub range: f64,
+    pub elevation: Adjustment,
+    pub windage: Adjustment,
+    /// Velocity, in ft/s.
+    pub velocity: f64,
+    /// Energy, in foot-pounds.
+    pub energy: f64,
+}
+
+/// A dope card for one load.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DopeCard {
+    pub title: String,
+    /// The rifle, load, zero and conditions the holds are for.
+    pub header: Vec<String>,
+    pub rows: Vec<DopeRow>,
+    setup: DopeCardSetup,
+    turret: Turret,
+    units: UnitSystem,
+}
+
+impl DopeCard {
+    /// A card from `points`, leaving out the muzzle.
+    pub fn new(
+        title: String,
+        header: Vec<String>,
+        points: &[TrajectoryPoint],
+        setup: DopeCardSetup,
+        turret: Turret,
+        units: UnitSystem,
+    ) -> Self {
+        let rows = points
+            .iter()
+            .filter(|point| point.range > 0.0)
+            .map(|point| DopeRow {
+                range: point.range,
+                elevation: turret.adjustment(point.drop, point.range),
+                windage: turret.adjustment(point.windage(), point.range),
+                velocity: point.velocity,
+                energy: point.energy,
+            })
+            .collect();
+        Self {
+            title,
+            header,
+            rows,
+            setup,
+            turret,
+            units,
+        }
+    }
+
+    fn headers(&self) -> [String; 7] {
+        let unit = self.turret.unit.label();
+        [
+            tr_args("Range ({0})", &[Quantity::Range.unit(self.units)]),
+            tr_args("Elevation ({0})", &[unit]),
+            tr("Clicks").to_string(),
+            tr_args("Wind ({0})", &[unit]),
+            tr("Clicks").to_string(),
+            tr_args("Velocity ({0})", &[Quantity::Velocity.unit(self.units)]),
+            tr_args("Energy ({0})", &[Quantity::Energy.unit(self.units)]),
+        ]
+    }
+
+    /// The row as printed: wind holds marked L or R instead of signed.
+    fn cells(&self, row: &DopeRow) -> [String; 7] {
+        // Sided by the value as printed, so a hold that rounds to nothing
+        // has no side
+        let side = |text: String| {
+            if text.trim_start_matches(['-', '0', '.', ',']).is_empty() {
+                text.trim_start_matches('-').to_string()
+            } else if let Some(text) = text.strip_prefix('-') {
+                format!("L {text}")
+            } else {
+                format!("R {text}")
+            }
+        };
+        [
+            format_number(Quantity::Range.display_value(self.units, row.range), 0),
+            format_number(row.elevation.angle, 2),
+            row.elevation.clicks.to_string(),
+            side(format_number(row.windage.angle, 2)),
+            side(row.windage.clicks.to_string()),
+            format_number(
+                Quantity::Velocity.display_value(self.units, row.velocity),
+                0,
+            ),
+            format_number(Quantity::Energy.display_value(self.units, row.energy), 0),
+        ]
+    }
+
+    /// The card as CSV, with the header as comment lines and signed holds,
+    /// windage positive right, for spreadsheets. Each number of clicks is
+    /// followed by what it leaves out.
+    pub fn to_csv(&self) -> String {
+        let field = |text: &str| {
+            if text.contains([',', '"', '\n']) {
+                format!("\"{}\"", text.replace('"', "\"\""))
+            } else {
+                text.to_string()
+            }
+        };
+        let mut lines = vec![format!("# {}", self.title)];
+        lines.extend(self.header.iter().map(|line| format!("# {line}")));
+        let mut headers = self.headers().to_vec();
+        headers.insert(5, tr("Residual").to_string());
+        headers.insert(3, tr("Residual").to_string());
+        lines.push(
+            headers
+                .iter()
+                .map(|header| field(header))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        // Without a sign on values that round to nothing, so an exact
+        // click reads 0.000 rather than -0.000
+        let number = |value: f64, decimals: usize| {
+            let text = format!("{value:.decimals$}");
+            if text.trim_start_matches(['-', '0', '.']).is_empty() {
+                text.trim_start_matches('-').to_string()
+            } else {
+                text
+            }
+        };
+        let adjustment = |adjustment: &Adjustment| {
+            format!(
+                "{},{},{}",
+                number(adjustment.angle, 2),
+                adjustment.clicks,
+                number(adjustment.residual, 3)
+            )
+        };
+        for row in &self.rows {
+            lines.push(
+                [
+                    format!(
+                        "{:.0}",
+                        Quantity::Range.display_value(self.units, row.range)
+                    ),
+                    adjustment(&row.elevation),
+                    adjustment(&row.windage),
+                    format!(
+                        "{:.0}",
+                        Quantity::Velocity.display_value(self.units, row.velocity)
+                    ),
+                    format!(
+                        "{:.0}",
+                        Quantity::Energy.display_value(self.units, row.energy)
+                    ),
+                ]
+                .join(","),
+            );
+        }
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
+    /// The card as a PDF of the chosen size, continued on further cards if
+    /// the rows don't fit on one.
+    pub fn to_pdf(&self) -> Document {
+        let (size, font_size, margin) = self.setup.size.layout();
+        let leading = font_size * 1.35;
+        let headers = self.headers();
+        let width = size.0 - 2.0 * margin;
+        // Range is narrow; the other columns share the rest
+        let range_width = width * 0.1;
+        let column_width = (width - range_width) / (headers.len() - 1) as f32;
+        let right_edge = |column: usize| margin + range_width + column_width * column as f32 - 2.0;
+
+        let mut document = Document::new(&self.title);
+        let mut rows = self.rows.iter().peekable();
+        loop {
+            let mut page = Page::new(size);
+            let mut y = margin + font_size * 1.3;
+            page.text(
+                margin,
+                y,
+                font_size * 1.3,
+                Font::Bold,
+                Align::Left,
+                &self.title,
+            );
+            y += leading;
+            for line in &self.header {
+                for line in pdf::wrap(line, Font::Regular, font_size * 0.85, width) {
+                    page.text(
+                        margin,
+                        y,
+                        font_size * 0.85,
+                        Font::Regular,
+                        Align::Left,
+                        &line,
+                    );
+                    y += font_size;
+                }
+            }
+            y += leading * 0.5;
+
+            // Column headings may be wider than their column, so they take
+            // two lines: the name and the unit
+            for (column, header) in headers.iter().enumerate() {
+                let (name, unit) = header.split_once(" (").unwrap_or((header, ""));
+                for (line, text) in [name, unit.trim_end_matches(')')].iter().enumerate() {
+                    page.text(
+                        right_edge(column),
+                        y + leading * line as f32,
+                        font_size,
+                        Font::Bold,
+                        Align::Right,
+                        text,
+                    );
+                }
+            }
+            // Rule under the units, then the first row
+            let rule = y + leading + font_size * 0.5;
+            page.line(
+                &[(margin, rule), (size.0 - margin, rule)],
+                0.5,
+                Color32::BLACK,
+                false,
+            );
+            y = rule + leading;
+
+            while y < size.1 - margin {
+                let Some(row) = rows.next() else {
+                    break;
+                };
+                for (column, cell) in self.cells(row).iter().enumerate() {
+                    let font = if column == 0 {
+                        Font::Bold
+                    } else {
+                        Font::Regular
+                    };
+                    page.text(right_edge(column), y, font_size, font, Align::Right, cell);
+                }
+                y += leading;
+            }
+            document.add_page(page);
+            if rows.peek().is_none() {
+                break;
+            }
+        }
+        document
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ballistics::AngleUnit;
+
+    fn card(setup: DopeCardSetup, turret: Turret) -> DopeCard {
+        let point = |range, drop, drift| TrajectoryPoint {
+            range,
+            drop,
+            drift,
+            velocity: 2000.0,
+            energy: 1500.0,
+            ..TrajectoryPoint::default()
+        };
+        let points: Vec<_> = (0..=20)
+            .map(|row| {
+                let range = row as f64 * 50.0;
+                // 2 MOA of drop and 1 MOA of drift right per 100 yd
+                let moa = range * 0.01047;
+                point(range, -2.0 * moa * range / 100.0, moa * range / 100.0)
+            })
+            .collect();
+        DopeCard::new(
+            "Match".to_string(),
+            vec!["Zero: 100 yd".to_string()],
+            &points,
+            setup,
+            turret,
+            UnitSystem::Imperial,
+        )
+    }
+
+    #[test]
+    fn test_holds_and_clicks() {
+        let card = card(DopeCardSetup::default(), Turret::default());
+        assert_eq!(card.rows.len(), 20);
+        let row = card.rows[9];
+        assert_eq!(row.range, 500.0);
+        assert!((row.elevation.angle - 10.0).abs() < 0.01, "{row:?}");
+        assert!((row.windage.angle + 5.0).abs() < 0.01, "{row:?}");
+        assert_eq!(row.elevation.clicks, 40);
+        assert_eq!(
+            card.cells(&row),
+            ["500", "10.00", "40", "L 5.00", "L 20", "2000", "1500"].map(String::from)
+        );
+
+        let mils = self::card(
+            DopeCardSetup::default(),
+            Turret {
+                unit: AngleUnit::Mil,
+                click: 0.1,
+            },
+        );
+        let elevation = mils.rows[9].elevation;
+        assert!((elevation.angle - 2.909).abs() < 0.001, "{elevation:?}");
+        assert_eq!(elevation.clicks, 29);
+        assert!((elevation.residual - 0.009).abs() < 0.001);
+
+        let still = DopeRow {
+            windage: Adjustment {
+                angle: -0.001,
+                clicks: 0,
+                residual: -0.001,
+            },
+            ..row
+        };
+        assert_eq!(card.cells(&still)[3..5], ["0.00", "0"].map(String::from));
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = card(DopeCardSetup::default(), Turret::default()).to_csv();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines[0], "# Match");
+        assert_eq!(lines[1], "# Zero: 100 yd");
+        assert_eq!(
+            lines[2],
+            "Range (yd),Elevation (MOA),Clicks,Residual,Wind (MOA),Clicks,Residual,Velocity (fps),Energy (ft·lbf)"
+        );
+        assert_eq!(lines[3], "50,1.00,4,0.000,-0.50,-2,0.000,2000,1500");
+        assert_eq!(lines.len(), 23);
+    }
+
+    #[test]
+    fn test_pdf_pages() {
+        for size in CardSize::ALL {
+            let card = card(
+                DopeCardSetup {
+                    size,
+                    ..DopeCardSetup::default()
+                },
+                Turret::default(),
+            );
+            let bytes = card.to_pdf().to_bytes();
+            let text = String::from_utf8_lossy(&bytes);
+            let pages = text.matches("/Type /Page ").count();
+            // Twenty rows need more than one small card
+            assert!(pages > 1, "{size:?}");
+            assert!(text.contains("(1000) Tj"));
+        }
+    }
+}
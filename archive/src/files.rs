@@ -0,0 +1,162 @@
+//! Saving and opening files (JSON, CSV and PDF): native file dialogs on
+//! desktop, browser downloads and uploads on the web.
+
+use std::sync::{Arc, Mutex};
+
+/// Kind of file being saved or opened, used for dialog filters and the
+/// download's content type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileType {
+    pub name: &'static str,
+    pub extension: &'static str,
+    pub mime: &'static str,
+}
+
+impl FileType {
+    pub const JSON: FileType = FileType {
+        name: "JSON",
+        extension: "json",
+        mime: "application/json",
+    };
+    pub const PDF: FileType = FileType {
+        name: "PDF",
+        extension: "pdf",
+        mime: "application/pdf",
+    };
+    pub const CSV: FileType = FileType {
+        name: "CSV",
+        extension: "csv",
+        mime: "text/csv",
+    };
+}
+
+/// Receives the contents of a file picked by the user.
+///
+/// The browser delivers the file asynchronously, so callers poll
+/// [`FileOpener::take`] every frame instead of getting the text back directly.
+#[derive(Default)]
+pub struct FileOpener {
+    received: Arc<Mutex<Option<Result<String, String>>>>,
+}
+
+impl FileOpener {
+    /// Asks the user for a text file of one of the given types.
+    pub fn open(&self, ctx: &egui::Context, file_types: &[FileType]) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = ctx;
+            let dialog = file_types
+                .iter()
+                .fold(rfd::FileDialog::new(), |dialog, file_type| {
+                    dialog.add_filter(file_type.name, &[file_type.extension])
+                });
+            if let Some(path) = dialog.pick_file() {
+                let result = std::fs::read_to_string(path).map_err(|err| err.to_string());
+                *self.received.lock().unwrap() = Some(result);
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        web::open(ctx.clone(), self.received.clone(), file_types);
+    }
+
+    /// Takes the contents of the last picked file, if one has arrived.
+    pub fn take(&self) -> Option<Result<String, String>> {
+        self.received.lock().unwrap().take()
+    }
+}
+
+/// Offers `contents` to the user as a file named `file_name`. Returns
+/// `Ok(false)` if the user cancelled.
+pub fn save(file_name: &str, contents: &[u8], file_type: FileType) -> Result<bool, String> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter(file_type.name, &[file_type.extension])
+            .set_file_name(file_name)
+            .save_file()
+        else {
+            return Ok(false);
+        };
+        std::fs::write(path, contents).map_err(|err| err.to_string())?;
+        Ok(true)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        web::download(file_name, contents, file_type)?;
+        Ok(true)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::sync::{Arc, Mutex};
+
+    use eframe::wasm_bindgen::{JsCast as _, JsValue, closure::Closure};
+    use wasm_bindgen_futures::JsFuture;
+
+    use super::FileType;
+
+    fn describe(err: JsValue) -> String {
+        err.as_string().unwrap_or_else(|| format!("{err:?}"))
+    }
+
+    pub fn download(file_name: &str, contents: &[u8], file_type: FileType) -> Result<(), String> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or("no document")?;
+
+        let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(contents));
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(file_type.mime);
+        let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+            .map_err(describe)?;
+        let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(describe)?;
+
+        let anchor: web_sys::HtmlAnchorElement = document
+            .create_element("a")
+            .map_err(describe)?
+            .unchecked_into();
+        anchor.set_href(&url);
+        anchor.set_download(file_name);
+        anchor.click();
+
+        web_sys::Url::revoke_object_url(&url).map_err(describe)
+    }
+
+    pub fn open(
+        ctx: egui::Context,
+        received: Arc<Mutex<Option<Result<String, String>>>>,
+        file_types: &[FileType],
+    ) {
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            return;
+        };
+        let Ok(input) = document.create_element("input") else {
+            return;
+        };
+        let input: web_sys::HtmlInputElement = input.unchecked_into();
+        input.set_type("file");
+        let accept: Vec<String> = file_types
+            .iter()
+            .map(|file_type| format!(".{},{}", file_type.extension, file_type.mime))
+            .collect();
+        input.set_accept(&accept.join(","));
+
+        let element = input.clone();
+        let on_change = Closure::once_into_js(move || {
+            let Some(file) = element.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = JsFuture::from(file.text())
+                    .await
+                    .map(|text| text.as_string().unwrap_or_default())
+                    .map_err(describe);
+                *received.lock().unwrap() = Some(result);
+                ctx.request_repaint();
+            });
+        });
+        input.set_onchange(Some(on_change.unchecked_ref()));
+        input.click();
+    }
+}
@@ -0,0 +1,146 @@
+/// Linear undo/redo history of application snapshots.
+///
+/// Recording a new state after undoing discards the redo branch, like most
+/// editors. The oldest states are dropped once `MAX_ENTRIES` is reached.
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    entries: Vec<T>,
+    current: usize,
+    /// Whether older entries have been dropped, so the first entry is no
+    /// longer the initial state.
+    truncated: bool,
+}
+
+const MAX_ENTRIES: usize = 100;
+
+impl<T: Clone + PartialEq> History<T> {
+    pub fn new(initial: T) -> Self {
+        Self {
+            entries: vec![initial],
+            current: 0,
+            truncated: false,
+        }
+    }
+
+    pub fn current(&self) -> &T {
+        &self.entries[self.current]
+    }
+
+    /// Records `state` if it differs from the current one. Returns whether a
+    /// new entry was added.
+    pub fn record(&mut self, state: T) -> bool {
+        if *self.current() == state {
+            return false;
+        }
+        self.entries.truncate(self.current + 1);
+        self.entries.push(state);
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries.remove(0);
+            self.truncated = true;
+        }
+        self.current = self.entries.len() - 1;
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.current > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.current + 1 < self.entries.len()
+    }
+
+    pub fn undo(&mut self) -> Option<&T> {
+        if !self.can_undo() {
+            return None;
+        }
+        self.current -= 1;
+        Some(self.current())
+    }
+
+    pub fn redo(&mut self) -> Option<&T> {
+        if !self.can_redo() {
+            return None;
+        }
+        self.current += 1;
+        Some(self.current())
+    }
+
+    /// Moves to the entry at `index`, keeping later entries available for redo.
+    pub fn jump_to(&mut self, index: usize) -> Option<&T> {
+        if index >= self.entries.len() {
+            return None;
+        }
+        self.current = index;
+        Some(self.current())
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    pub fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_redo() {
+        let mut history = History::new(1);
+        assert!(history.record(2));
+        assert!(history.record(3));
+        assert!(!history.record(3));
+
+        assert_eq!(history.undo(), Some(&2));
+        assert_eq!(history.undo(), Some(&1));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(&2));
+        assert_eq!(history.redo(), Some(&3));
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn test_record_after_undo_discards_redo() {
+        let mut history = History::new(1);
+        history.record(2);
+        history.record(3);
+        history.undo();
+        history.record(4);
+
+        assert_eq!(history.entries(), &[1, 2, 4]);
+        assert!(!history.can_redo());
+        assert!(!history.is_truncated());
+    }
+
+    #[test]
+    fn test_jump_to_keeps_later_entries() {
+        let mut history = History::new(1);
+        history.record(2);
+        history.record(3);
+
+        assert_eq!(history.jump_to(0), Some(&1));
+        assert_eq!(history.entries().len(), 3);
+        assert_eq!(history.redo(), Some(&2));
+        assert_eq!(history.jump_to(5), None);
+    }
+
+    #[test]
+    fn test_oldest_entries_are_dropped() {
+        let mut history = History::new(0);
+        for i in 1..=MAX_ENTRIES + 10 {
+            history.record(i);
+        }
+        assert_eq!(history.entries().len(), MAX_ENTRIES);
+        assert_eq!(history.entries()[0], 11);
+        assert!(history.is_truncated());
+        assert_eq!(*history.current(), MAX_ENTRIES + 10);
+    }
+}
@@ -0,0 +1,734 @@
+//! Translation catalog and locale-aware number formatting.
+//!
+//! UI text is looked up by its English wording, so untranslated strings fall
+//! back to English. Templates use numbered placeholders (`{0}`, `{1}`, ...) so
+//! translations can reorder them.
+
+use std::cell::Cell;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::German];
+
+    /// Name of the language in that language, so users can always find their own.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        }
+    }
+
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Language::English => '.',
+            Language::German => ',',
+        }
+    }
+
+    fn catalog(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => &[],
+            Language::German => GERMAN,
+        }
+    }
+}
+
+thread_local! {
+    static LANGUAGE: Cell<Language> = const { Cell::new(Language::English) };
+}
+
+/// Sets the language used by [`tr`] and [`format_number`] on this thread.
+pub fn set_language(language: Language) {
+    LANGUAGE.with(|current| current.set(language));
+}
+
+pub fn language() -> Language {
+    LANGUAGE.with(Cell::get)
+}
+
+/// Translates `text` into the current language.
+pub fn tr(text: &'static str) -> &'static str {
+    language()
+        .catalog()
+        .iter()
+        .find(|(english, _)| *english == text)
+        .map_or(text, |(_, translated)| translated)
+}
+
+/// Translates a template and fills in its numbered placeholders.
+pub fn tr_args(template: &'static str, args: &[&str]) -> String {
+    args.iter()
+        .enumerate()
+        .fold(tr(template).to_string(), |text, (i, arg)| {
+            text.replace(&format!("{{{i}}}"), arg)
+        })
+}
+
+/// Formats a number with the current language's decimal separator.
+pub fn format_number(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value);
+    match language().decimal_separator() {
+        '.' => text,
+        separator => text.replace('.', &separator.to_string()),
+    }
+}
+
+/// Formats a plot axis tick with just enough decimals for the tick spacing.
+pub fn format_tick(value: f64, step_size: f64) -> String {
+    let decimals = (-step_size.log10().floor()).max(0.0) as usize;
+    format_number(value, decimals)
+}
+
+/// Parses a number typed with either a decimal point or a decimal comma.
+pub fn parse_number(text: &str) -> Option<f64> {
+    text.trim().replace(',', ".").parse().ok()
+}
+
+const GERMAN: &[(&str, &str)] = &[
+    // Navigation and settings
+    ("TOP Gun", "TOP Gun"),
+    ("Ballistics", "Ballistik"),
+    ("MPBR", "MPBR"),
+    ("TOP Gun Calculator", "TOP-Gun-Rechner"),
+    (
+        "Maximum Point Blank Range",
+        "Maximale Fleckschussentfernung",
+    ),
+    ("⚙ Settings", "⚙ Einstellungen"),
+    ("Units:", "Einheiten:"),
+    ("Theme:", "Design:"),
+    ("Plot colours:", "Diagrammfarben:"),
+    ("Language:", "Sprache:"),
+    ("Undo", "Rückgängig"),
+    ("Redo", "Wiederholen"),
+    ("History", "Verlauf"),
+    ("Initial state", "Ausgangszustand"),
+    ("Oldest kept state", "Ältester gespeicherter Zustand"),
+    ("Added {0}", "{0} hinzugefügt"),
+    ("Removed {0}", "{0} entfernt"),
+    ("Edited {0}", "{0} bearbeitet"),
+    ("Primary load:", "Hauptlaborierung:"),
+    // Setup library
+    ("📚 Setups", "📚 Konfigurationen"),
+    ("Setup library", "Konfigurationsbibliothek"),
+    ("Name:", "Name:"),
+    ("Rifle:", "Gewehr:"),
+    ("Load:", "Laborierung:"),
+    ("Notes:", "Notizen:"),
+    ("Save current inputs", "Aktuelle Eingaben speichern"),
+    (
+        "Replaces a saved setup with the same name.",
+        "Ersetzt eine gespeicherte Konfiguration mit demselben Namen.",
+    ),
+    ("Saved {0}.", "{0} gespeichert."),
+    ("Loaded {0}.", "{0} geladen."),
+    (
+        "No saved setups yet.",
+        "Noch keine Konfigurationen gespeichert.",
+    ),
+    ("Load", "Laden"),
+    ("Delete", "Löschen"),
+    ("Import…", "Importieren…"),
+    ("Export…", "Exportieren…"),
+    ("Imported {0} setups.", "{0} Konfigurationen importiert."),
+    ("Exported {0} setups.", "{0} Konfigurationen exportiert."),
+    (
+        "Setup names must not be empty.",
+        "Konfigurationsnamen dürfen nicht leer sein.",
+    ),
+    ("Setup \"{0}\": {1}", "Konfiguration „{0}“: {1}"),
+    (
+        "This is not a valid setup file: {0}",
+        "Dies ist keine gültige Konfigurationsdatei: {0}",
+    ),
+    (
+        "This is not a setup file.",
+        "Dies ist keine Konfigurationsdatei.",
+    ),
+    (
+        "Setup file version {0} needs a newer version of this app.",
+        "Konfigurationsdatei-Version {0} erfordert eine neuere Version dieser App.",
+    ),
+    ("Imperial", "Imperial"),
+    ("Metric", "Metrisch"),
+    ("System", "System"),
+    ("Light", "Hell"),
+    ("Dark", "Dunkel"),
+    ("Classic", "Klassisch"),
+    ("Colour-blind safe", "Farbenblind-sicher"),
+    ("High contrast", "Hoher Kontrast"),
+    // TOP inputs
+    ("Graph:", "Diagramm:"),
+    ("Projectile", "Geschoss"),
+    ("Velocity", "Geschwindigkeit"),
+    ("Rifle", "Gewehr"),
+    ("Projectile weight", "Geschossgewicht"),
+    ("Muzzle velocity", "Mündungsgeschwindigkeit"),
+    ("Rifle weight", "Gewehrgewicht"),
+    ("Rifle Weight", "Gewehrgewicht"),
+    ("Projectile Weight", "Geschossgewicht"),
+    ("1 MOA @ {0}", "1 MOA bei {0}"),
+    ("{0} must be a number.", "{0} muss eine Zahl sein."),
+    (
+        "{0} must be greater than zero.",
+        "{0} muss größer als null sein.",
+    ),
+    (
+        "{0} of {1} is outside the typical range of {2}–{3}.",
+        "{0} von {1} liegt außerhalb des üblichen Bereichs von {2}–{3}.",
+    ),
+    (
+        "Correct the inputs above to see the plot.",
+        "Korrigieren Sie die Eingaben oben, um das Diagramm zu sehen.",
+    ),
+    // TOP report
+    ("📄 PDF report", "📄 PDF-Bericht"),
+    (
+        "Save a printable one-page summary of this analysis.",
+        "Eine druckbare einseitige Zusammenfassung dieser Analyse speichern.",
+    ),
+    (
+        "Correct all three inputs, including the graphed one, to save a report.",
+        "Korrigieren Sie alle drei Eingaben, auch die im Diagramm dargestellte, um einen Bericht zu speichern.",
+    ),
+    ("Inputs", "Eingaben"),
+    ("Results", "Ergebnisse"),
+    ("Kinetic energy:", "Kinetische Energie:"),
+    (
+        "Expected 5-round group:",
+        "Erwarteter Streukreis (5 Schuss):",
+    ),
+    ("{0} for 1 MOA:", "{0} für 1 MOA:"),
+    // TOP plot
+    ("Rifle Weight ({0})", "Gewehrgewicht ({0})"),
+    ("Muzzle Velocity ({0})", "Mündungsgeschwindigkeit ({0})"),
+    ("Projectile Weight ({0})", "Geschossgewicht ({0})"),
+    ("5-Round Group Size (MOA)", "Streukreis 5 Schuss (MOA)"),
+    ("2σ (95%)", "2σ (95 %)"),
+    ("1σ (68%)", "1σ (68 %)"),
+    ("Expected Precision", "Erwartete Präzision"),
+    ("{0} versus {1}.", "{0} in Abhängigkeit von {1}."),
+    (
+        "At {0} {1}: expected {2} MOA, 1σ band {3}–{4} MOA ({5} MOA wide), 2σ band {6}–{7} MOA ({8} MOA wide).",
+        "Bei {0} {1}: erwartet {2} MOA, 1σ-Band {3}–{4} MOA ({5} MOA breit), 2σ-Band {6}–{7} MOA ({8} MOA breit).",
+    ),
+    // TOP footer
+    (
+        "Welcome to the r/longrange TOP Gun calculator.",
+        "Willkommen beim TOP-Gun-Rechner von r/longrange.",
+    ),
+    (
+        "This calculator is based on the TOP (Theory of Precision) Gun formula published by Applied Ballistics in Modern Advancements in Long Range Shooting, Vol 3.",
+        "Dieser Rechner basiert auf der TOP-Gun-Formel (Theory of Precision), veröffentlicht von Applied Ballistics in Modern Advancements in Long Range Shooting, Band 3.",
+    ),
+    (
+        "This tool is provided for free by the moderator team of r/Longrange to help answer shooter questions and manage expectations for the precision (group size) of a given rifle.",
+        "Dieses Werkzeug wird vom Moderatorenteam von r/Longrange kostenlos bereitgestellt, um Fragen von Schützen zu beantworten und realistische Erwartungen an die Präzision (Streukreis) eines Gewehrs zu vermitteln.",
+    ),
+    (
+        "Results from this tool are an estimate only, and rely on the use of a rifle and optic in good condition with no mechanical issues (scope problems, loose screws, etc) and commercial match grade ammo or comparable hand loads.",
+        "Die Ergebnisse sind nur eine Schätzung und setzen ein Gewehr und eine Optik in gutem Zustand ohne mechanische Probleme (Zielfernrohrfehler, lose Schrauben usw.) sowie handelsübliche Matchmunition oder vergleichbare Wiederladungen voraus.",
+    ),
+    ("Community:", "Community:"),
+    ("Original:", "Original:"),
+    (
+        "TOP Gun Calculator Spreadsheet",
+        "TOP-Gun-Rechner (Tabelle)",
+    ),
+    // Ballistics page
+    ("Loads", "Laborierungen"),
+    ("＋ Add Load", "＋ Laborierung hinzufügen"),
+    ("New Load", "Neue Laborierung"),
+    ("Add New Load", "Neue Laborierung hinzufügen"),
+    ("Edit Load", "Laborierung bearbeiten"),
+    ("Edit", "Bearbeiten"),
+    ("Save", "Speichern"),
+    ("Cancel", "Abbrechen"),
+    ("Weight", "Gewicht"),
+    ("Bullet weight", "Geschossgewicht"),
+    ("BC:", "BC:"),
+    (
+        "Drag model the BC is referenced to",
+        "Widerstandsmodell, auf das sich der BC bezieht",
+    ),
+    (
+        "Load names must not be empty.",
+        "Namen von Laborierungen dürfen nicht leer sein.",
+    ),
+    (
+        "BC must be greater than zero.",
+        "Der BC muss größer als null sein.",
+    ),
+    (
+        "A BC of {0} is outside the typical range of {1}–{2}.",
+        "Ein BC von {0} liegt außerhalb des üblichen Bereichs von {1}–{2}.",
+    ),
+    // Drag models
+    (
+        "Flat base, blunt nose. Most published BCs use it.",
+        "Flacher Boden, stumpfe Spitze. Die meisten veröffentlichten BCs beziehen sich darauf.",
+    ),
+    (
+        "Conical nose, long boat-tail (Aberdeen J).",
+        "Kegelförmige Spitze, langes Boattail (Aberdeen J).",
+    ),
+    (
+        "Short boat-tail, tangent ogive.",
+        "Kurzes Boattail, Tangentenogive.",
+    ),
+    (
+        "Flat base, long secant ogive.",
+        "Flacher Boden, lange Sekantenogive.",
+    ),
+    (
+        "Long boat-tail, long ogive. Suits modern match bullets.",
+        "Langes Boattail, lange Ogive. Passt zu modernen Matchgeschossen.",
+    ),
+    (
+        "Flat base, very long secant ogive.",
+        "Flacher Boden, sehr lange Sekantenogive.",
+    ),
+    (
+        "Ingalls' tables, for old published BCs.",
+        "Ingalls-Tabellen, für ältere veröffentlichte BCs.",
+    ),
+    // Custom drag curves
+    ("Custom drag curve", "Eigene Widerstandskurve"),
+    ("Drag curve:", "Widerstandskurve:"),
+    (
+        "{0} points from Mach {1} to {2}",
+        "{0} Punkte von Mach {1} bis {2}",
+    ),
+    ("Use a BC instead", "Stattdessen einen BC verwenden"),
+    ("Import drag curve…", "Widerstandskurve importieren…"),
+    (
+        "A CSV or JSON file of Mach and Cd points",
+        "Eine CSV- oder JSON-Datei mit Mach- und Cd-Punkten",
+    ),
+    (
+        "A drag curve needs the bullet's diameter.",
+        "Eine Widerstandskurve braucht den Geschossdurchmesser.",
+    ),
+    (
+        "A drag curve needs at least two points.",
+        "Eine Widerstandskurve braucht mindestens zwei Punkte.",
+    ),
+    (
+        "Point {0}: Mach must not be negative.",
+        "Punkt {0}: Mach darf nicht negativ sein.",
+    ),
+    (
+        "Point {0}: Mach numbers must increase from point to point.",
+        "Punkt {0}: Die Mach-Zahlen müssen von Punkt zu Punkt steigen.",
+    ),
+    (
+        "Point {0}: a Cd of {1} is not between 0 and {2}.",
+        "Punkt {0}: Ein Cd von {1} liegt nicht zwischen 0 und {2}.",
+    ),
+    (
+        "This is not a valid drag curve file: {0}",
+        "Dies ist keine gültige Widerstandskurven-Datei: {0}",
+    ),
+    (
+        "Line {0}: expected a Mach number and a Cd.",
+        "Zeile {0}: Mach-Zahl und Cd erwartet.",
+    ),
+    // Velocity-banded BCs
+    ("BC band:", "BC-Stufe:"),
+    ("from", "ab"),
+    (
+        "The BC applies at and above this velocity",
+        "Der BC gilt ab dieser Geschwindigkeit",
+    ),
+    ("Remove band", "Stufe entfernen"),
+    ("＋ Add BC band", "＋ BC-Stufe hinzufügen"),
+    (
+        "Use a different BC above a velocity, as published for some bullets",
+        "Ab einer Geschwindigkeit einen anderen BC verwenden, wie für manche Geschosse angegeben",
+    ),
+    (
+        "Below the slowest BC band",
+        "Unterhalb der langsamsten BC-Stufe",
+    ),
+    (
+        "BC band velocities must be greater than zero.",
+        "Die Geschwindigkeiten der BC-Stufen müssen größer als null sein.",
+    ),
+    (
+        "Two BC bands start at {0}.",
+        "Zwei BC-Stufen beginnen bei {0}.",
+    ),
+    ("{0} {1}, {2} bands", "{0} {1}, {2} Stufen"),
+    ("{0} from {1}", "{0} ab {1}"),
+    ("{0} below", "{0} darunter"),
+    // Environment and wind
+    ("Environment", "Umgebung"),
+    ("Temperature:", "Temperatur:"),
+    ("Air:", "Luft:"),
+    ("Pressure", "Luftdruck"),
+    ("Density altitude", "Dichtehöhe"),
+    ("Density altitude:", "Dichtehöhe:"),
+    ("Pressure:", "Luftdruck:"),
+    ("Station", "Stationsdruck"),
+    ("Sea level", "Meereshöhe"),
+    (
+        "Absolute pressure where you are, as a weather meter reads it",
+        "Absoluter Luftdruck am Standort, wie ihn ein Wettermesser anzeigt",
+    ),
+    (
+        "Barometric pressure corrected to sea level, as weather reports give it",
+        "Auf Meereshöhe reduzierter Luftdruck, wie ihn der Wetterbericht angibt",
+    ),
+    ("Altitude:", "Höhe:"),
+    ("Humidity:", "Luftfeuchtigkeit:"),
+    ("Density altitude: {0}", "Dichtehöhe: {0}"),
+    ("Wind from:", "Wind ab:"),
+    (
+        "Range this wind starts at",
+        "Entfernung, ab der dieser Wind weht",
+    ),
+    ("Remove zone", "Zone entfernen"),
+    ("Wind:", "Wind:"),
+    (
+        "Direction the wind blows from, clockwise from the target",
+        "Richtung, aus der der Wind weht, im Uhrzeigersinn vom Ziel aus",
+    ),
+    ("＋ Add wind zone", "＋ Windzone hinzufügen"),
+    ("Headwind", "Gegenwind"),
+    ("R Front", "R vorne"),
+    ("R Cross", "R quer"),
+    ("R Rear", "R hinten"),
+    ("Tailwind", "Rückenwind"),
+    ("L Rear", "L hinten"),
+    ("L Cross", "L quer"),
+    ("L Front", "L vorne"),
+    // Spin drift and the trajectory table
+    ("Firearm", "Waffe"),
+    ("Sight height", "Visierhöhe"),
+    ("Zero", "Einschuss"),
+    ("Zero range", "Einschießentfernung"),
+    ("Twist", "Drall"),
+    ("Twist rate", "Dralllänge"),
+    (
+        "Barrel length per turn of the rifling",
+        "Lauflänge pro Umdrehung der Züge",
+    ),
+    ("Right-hand", "Rechtsdrall"),
+    ("Left-hand", "Linksdrall"),
+    ("Trajectory table", "Flugbahntabelle"),
+    ("Max range", "Max. Entfernung"),
+    ("Table max range", "Maximale Tabellenentfernung"),
+    ("Step", "Schritt"),
+    ("Table step size", "Tabellenschrittweite"),
+    (
+        "Add a load to see its trajectory.",
+        "Fügen Sie eine Laborierung hinzu, um ihre Flugbahn zu sehen.",
+    ),
+    (
+        "Correct the inputs to see the trajectory.",
+        "Korrigieren Sie die Eingaben, um die Flugbahn zu sehen.",
+    ),
+    ("Stability factor (Sg): {0}", "Stabilitätsfaktor (Sg): {0}"),
+    (
+        "Below 1.5 the bullet is not fully stable and loses BC; below 1 it tumbles.",
+        "Unter 1,5 ist das Geschoss nicht voll stabil und verliert BC; unter 1 überschlägt es sich.",
+    ),
+    (
+        "Enter the bullet's diameter and length and the barrel twist to include spin drift.",
+        "Geben Sie Durchmesser und Länge des Geschosses und den Drall ein, um die Drallabweichung einzubeziehen.",
+    ),
+    ("Range ({0})", "Entfernung ({0})"),
+    ("Drop ({0})", "Fall ({0})"),
+    ("Wind drift ({0})", "Windabdrift ({0})"),
+    ("Spin drift ({0})", "Drallabweichung ({0})"),
+    ("Windage ({0})", "Seitenkorrektur ({0})"),
+    ("Velocity ({0})", "Geschwindigkeit ({0})"),
+    ("Energy ({0})", "Energie ({0})"),
+    ("TOF (s)", "Flugzeit (s)"),
+    // Earth rotation
+    ("Earth rotation", "Erdrotation"),
+    (
+        "Correct for Coriolis and Eötvös effects",
+        "Coriolis- und Eötvös-Effekt berücksichtigen",
+    ),
+    (
+        "The Earth turning under the bullet moves long shots by a few inches",
+        "Die Erddrehung unter dem Geschoss verschiebt weite Schüsse um einige Zentimeter",
+    ),
+    ("Latitude:", "Breitengrad:"),
+    (
+        "Positive north of the equator, negative south",
+        "Positiv nördlich des Äquators, negativ südlich",
+    ),
+    ("Azimuth of fire:", "Schussrichtung (Azimut):"),
+    (
+        "Direction of the shot, clockwise from true north",
+        "Richtung des Schusses, im Uhrzeigersinn von geografisch Nord",
+    ),
+    ("Coriolis ({0})", "Coriolis ({0})"),
+    ("Eötvös ({0})", "Eötvös ({0})"),
+    ("Aero jump ({0})", "Aerodyn. Sprung ({0})"),
+    ("Bullet diameter:", "Geschossdurchmesser:"),
+    ("Bullet length:", "Geschosslänge:"),
+    (
+        "Leave at zero if unknown; needed for spin drift",
+        "Null lassen, wenn unbekannt; für die Drallabweichung nötig",
+    ),
+    (
+        "Needed for the drag curve and spin drift",
+        "Für die Widerstandskurve und die Drallabweichung nötig",
+    ),
+    (
+        "Bullet dimensions must not be negative.",
+        "Geschossmaße dürfen nicht negativ sein.",
+    ),
+    (
+        "Spin drift needs both the bullet's diameter and length.",
+        "Die Drallabweichung braucht Durchmesser und Länge des Geschosses.",
+    ),
+    // Inclined shots
+    ("Look angle", "Schusswinkel"),
+    ("Angle:", "Winkel:"),
+    (
+        "Positive uphill, negative downhill",
+        "Positiv bergauf, negativ bergab",
+    ),
+    ("Cosine:", "Kosinus:"),
+    (
+        "As an angle cosine indicator reads it. Keeps the shot uphill or downhill.",
+        "Wie ihn ein Winkelkosinusanzeiger abliest. Bergauf bleibt bergauf, bergab bleibt bergab.",
+    ),
+    (
+        "The rifleman's rule column holds for the horizontal distance, for comparison.",
+        "Die Spalte Faustregel hält zum Vergleich für die waagerechte Entfernung.",
+    ),
+    ("Rifleman's rule ({0})", "Faustregel ({0})"),
+    // Zeroing
+    (
+        "The zero range must be greater than zero.",
+        "Die Einschießentfernung muss größer als null sein.",
+    ),
+    (
+        "The zero offset is out of reach.",
+        "Die Treffpunktlage ist nicht erreichbar.",
+    ),
+    (
+        "The bullet can't reach the zero range.",
+        "Das Geschoss erreicht die Einschießentfernung nicht.",
+    ),
+    (
+        "The zero search did not converge.",
+        "Die Einschusssuche ist nicht konvergiert.",
+    ),
+    ("Impact high:", "Treffpunkt hoch:"),
+    (
+        "Where the bullet hits above the aim at the zero range; negative for low",
+        "Wie weit das Geschoss auf der Einschießentfernung über dem Haltepunkt trifft; negativ für tief",
+    ),
+    ("Impact right:", "Treffpunkt rechts:"),
+    (
+        "Where the bullet hits right of the aim at the zero range; negative for left",
+        "Wie weit das Geschoss auf der Einschießentfernung rechts vom Haltepunkt trifft; negativ für links",
+    ),
+    (
+        "Zeroed in different conditions",
+        "Unter anderen Bedingungen eingeschossen",
+    ),
+    (
+        "The zero shifts with the air, so give the temperature and station pressure on the day it was set",
+        "Der Einschuss wandert mit der Luft, daher Temperatur und Stationsdruck vom Tag des Einschießens angeben",
+    ),
+    // Primary and secondary loads
+    ("Zeros", "Nullpunkte"),
+    ("Near zero", "Naher Nullpunkt"),
+    ("Far zero", "Ferner Nullpunkt"),
+    ("Primary load", "Hauptlaborierung"),
+    ("beyond {0}", "jenseits {0}"),
+    ("No crossing", "Kein Schnittpunkt"),
+    (
+        "This load never rises to the line of sight on the primary load's zero.",
+        "Diese Laborierung erreicht mit dem Einschuss der Hauptlaborierung nie die Visierlinie.",
+    ),
+    (
+        "Primary load: the rifle is zeroed for it, and the others are fired with its zero",
+        "Hauptlaborierung: Die Waffe ist auf sie eingeschossen, die anderen werden mit ihrem Einschuss verschossen",
+    ),
+    (
+        "Fired with the zero for {0}",
+        "Mit dem Einschuss für {0} verschossen",
+    ),
+    // Maximum point-blank range
+    ("Vital zone", "Vitalzone"),
+    ("Vital zone diameter", "Durchmesser der Vitalzone"),
+    (
+        "Sight height and air are taken from the ballistics page. The zero is found in still air on level ground.",
+        "Visierhöhe und Luft stammen aus der Ballistikseite. Der Einschuss wird bei Windstille auf ebenem Gelände ermittelt.",
+    ),
+    ("Optimal zero:", "Optimaler Einschuss:"),
+    ("Maximum ordinate:", "Maximale Scheitelhöhe:"),
+    ("Enters the vital zone:", "Erreicht die Vitalzone:"),
+    ("MPBR:", "MPBR:"),
+    ("Height ({0})", "Höhe ({0})"),
+    ("Line of sight", "Visierlinie"),
+    ("Trajectory", "Flugbahn"),
+    (
+        "Add a load on the ballistics page to find its MPBR.",
+        "Fügen Sie auf der Ballistikseite eine Laborierung hinzu, um ihre MPBR zu ermitteln.",
+    ),
+    (
+        "Correct the inputs to see the MPBR.",
+        "Korrigieren Sie die Eingaben, um die MPBR zu sehen.",
+    ),
+    (
+        "The vital zone must be greater than zero.",
+        "Die Vitalzone muss größer als null sein.",
+    ),
+    (
+        "The bullet can't rise to the top of the vital zone.",
+        "Das Geschoss kann den oberen Rand der Vitalzone nicht erreichen.",
+    ),
+    (
+        "The point-blank range is beyond the range searched.",
+        "Die Fleckschussentfernung liegt jenseits des durchsuchten Bereichs.",
+    ),
+    (
+        "The point-blank range search did not converge.",
+        "Die Suche nach der Fleckschussentfernung ist nicht konvergiert.",
+    ),
+    // Trajectory chart
+    ("Trajectory comparison", "Flugbahnvergleich"),
+    ("Drop", "Fall"),
+    ("Windage", "Seitenkorrektur"),
+    (
+        "{0}: drop {1}, drift {2}, {3}, {4}, {5} s",
+        "{0}: Fall {1}, Abdrift {2}, {3}, {4}, {5} s",
+    ),
+    // Dope card
+    ("Dope card", "Schusstafel"),
+    ("Dope card step size", "Schrittweite der Schusstafel"),
+    ("Card:", "Karte:"),
+    ("Index card (5 × 3 in)", "Karteikarte (5 × 3 Zoll)"),
+    ("Wrist coach (4 × 2.5 in)", "Armbandtafel (4 × 2,5 Zoll)"),
+    (
+        "Save a printable dope card for the load in the table.",
+        "Eine druckbare Schusstafel für die Laborierung in der Tabelle speichern.",
+    ),
+    (
+        "Save the dope card as a spreadsheet.",
+        "Die Schusstafel als Tabelle speichern.",
+    ),
+    ("Elevation ({0})", "Höhenkorrektur ({0})"),
+    ("Wind ({0})", "Wind ({0})"),
+    ("Clicks", "Klicks"),
+    ("Zero:", "Einschuss:"),
+    ("{0} for {1}", "{0} für {1}"),
+    (
+        "sight height {0}, twist 1:{1}, {2}",
+        "Visierhöhe {0}, Drall 1:{1}, {2}",
+    ),
+    ("density altitude {0}", "Dichtehöhe {0}"),
+    ("{0}% humidity", "{0} % Luftfeuchte"),
+    ("{0} from {1}°", "{0} aus {1}°"),
+    ("{0} zones", "{0} Zonen"),
+    // Turret
+    ("Turret:", "Absehenverstellung:"),
+    ("Click:", "Klick:"),
+    (
+        "True minutes of angle, 1.047 in at 100 yd",
+        "Echte Winkelminuten, 1,047 Zoll auf 100 yd",
+    ),
+    (
+        "Shooter's MOA, 1 in at 100 yd",
+        "Schützen-MOA, 1 Zoll auf 100 yd",
+    ),
+    (
+        "Milliradians, 3.6 in at 100 yd",
+        "Milliradiant, 3,6 Zoll auf 100 yd",
+    ),
+    ("Residual", "Rest"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_is_identity() {
+        set_language(Language::English);
+        assert_eq!(tr("Rifle weight"), "Rifle weight");
+        assert_eq!(tr_args("1 MOA @ {0}", &["12.00 lbs"]), "1 MOA @ 12.00 lbs");
+    }
+
+    #[test]
+    fn test_german_lookup_and_fallback() {
+        set_language(Language::German);
+        assert_eq!(tr("Rifle weight"), "Gewehrgewicht");
+        assert_eq!(tr("Not in the catalog"), "Not in the catalog");
+        assert_eq!(
+            tr_args("{0} must be greater than zero.", &["Gewehrgewicht"]),
+            "Gewehrgewicht muss größer als null sein."
+        );
+        set_language(Language::English);
+    }
+
+    /// Indices of the `{n}` placeholders in a template, in order.
+    fn placeholders(template: &str) -> Vec<usize> {
+        let mut found: Vec<usize> = template
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}')?.0.parse().ok())
+            .collect();
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+
+    #[test]
+    fn test_catalog_placeholders_match() {
+        for (english, german) in GERMAN {
+            let expected = placeholders(english);
+            assert!(expected.iter().all(|&i| i <= 8), "{english}");
+            assert_eq!(placeholders(german), expected, "{english}");
+        }
+    }
+
+    #[test]
+    fn test_catalog_has_no_duplicates() {
+        for (i, (english, _)) in GERMAN.iter().enumerate() {
+            assert!(
+                GERMAN[i + 1..].iter().all(|(other, _)| other != english),
+                "{english}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decimal_comma() {
+        set_language(Language::German);
+        assert_eq!(format_number(1.092, 2), "1,09");
+        set_language(Language::English);
+        assert_eq!(format_number(1.092, 2), "1.09");
+    }
+
+    #[test]
+    fn test_tick_decimals_follow_step() {
+        assert_eq!(format_tick(1500.0, 500.0), "1500");
+        assert_eq!(format_tick(1.25, 0.05), "1.25");
+        assert_eq!(format_tick(0.5, 0.1), "0.5");
+    }
+
+    #[test]
+    fn test_parse_either_separator() {
+        assert_eq!(parse_number("12,5"), Some(12.5));
+        assert_eq!(parse_number(" 12.5 "), Some(12.5));
+        assert_eq!(parse_number("abc"), None);
+    }
+}
@@ -0,0 +1,172 @@
+use egui::{DragValue, Response, Slider, SliderClamping, Ui};
+
+use crate::i18n::{format_number, parse_number, tr, tr_args};
+use crate::units::{Quantity, UnitSystem};
+
+/// Result of checking a value against an input's limits.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Validation {
+    Ok,
+    /// Outside the typical range; the value is still used.
+    Warning(String),
+    /// The value cannot be used in the calculation.
+    Error(String),
+}
+
+impl Validation {
+    pub fn is_error(&self) -> bool {
+        matches!(self, Validation::Error(_))
+    }
+}
+
+/// A numeric calculator input: a slider over the typical range paired with a
+/// typed field that accepts any value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericInput {
+    /// Short label shown next to the slider.
+    pub label: &'static str,
+    /// Name used in validation messages.
+    pub name: &'static str,
+    pub quantity: Quantity,
+    /// Typical range, in stored units.
+    pub soft_min: f64,
+    pub soft_max: f64,
+}
+
+impl NumericInput {
+    /// Values must be finite and positive; anything outside the soft range is
+    /// accepted with a warning.
+    pub fn validate(&self, value: f64, units: UnitSystem) -> Validation {
+        let name = tr(self.name);
+        if !value.is_finite() {
+            Validation::Error(tr_args("{0} must be a number.", &[name]))
+        } else if value <= 0.0 {
+            Validation::Error(tr_args("{0} must be greater than zero.", &[name]))
+        } else if value < self.soft_min || value > self.soft_max {
+            let soft_min = format_number(
+                self.quantity.display_value(units, self.soft_min),
+                self.quantity.decimals(units),
+            );
+            Validation::Warning(tr_args(
+                "{0} of {1} is outside the typical range of {2}–{3}.",
+                &[
+                    name,
+                    &self.quantity.format(units, value),
+                    &soft_min,
+                    &self.quantity.format(units, self.soft_max),
+                ],
+            ))
+        } else {
+            Validation::Ok
+        }
+    }
+
+    /// Draws the label, slider and typed field into the current layout. The
+    /// value is stored in imperial units and edited in `units`.
+    pub fn show(&self, ui: &mut Ui, value: &mut f64, units: UnitSystem) -> Response {
+        let quantity = self.quantity;
+        let decimals = quantity.decimals(units);
+        let soft_min = quantity.display_value(units, self.soft_min);
+        let soft_max = quantity.display_value(units, self.soft_max);
+
+        let label = ui.label(format!("{}:", tr(self.label)));
+        let slider = ui
+            .add(
+                Slider::from_get_set(soft_min..=soft_max, |new| {
+                    if let Some(new) = new {
+                        *value = quantity.stored_value(units, new);
+                    }
+                    quantity.display_value(units, *value)
+                })
+                .clamping(SliderClamping::Never)
+                .step_by(10f64.powi(-(decimals as i32)))
+                .show_value(false),
+            )
+            .labelled_by(label.id);
+        let field = ui
+            .add(
+                DragValue::from_get_set(|new| {
+                    if let Some(new) = new {
+                        *value = quantity.stored_value(units, new);
+                    }
+                    quantity.display_value(units, *value)
+                })
+                .speed((soft_max - soft_min) / 500.0)
+                .max_decimals(decimals)
+                .custom_formatter(|value, _| format_number(value, decimals))
+                .custom_parser(parse_number)
+                // Commit typed values on Enter so each edit is one history entry
+                .update_while_editing(false)
+                .suffix(format!(" {}", quantity.unit(units))),
+            )
+            .labelled_by(label.id);
+        slider | field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n;
+
+    const RIFLE: NumericInput = NumericInput {
+        label: "Rifle",
+        name: "Rifle weight",
+        quantity: Quantity::RifleWeight,
+        soft_min: 5.0,
+        soft_max: 50.0,
+    };
+
+    #[test]
+    fn test_value_in_range_is_ok() {
+        assert_eq!(RIFLE.validate(12.0, UnitSystem::Imperial), Validation::Ok);
+        assert_eq!(RIFLE.validate(5.0, UnitSystem::Imperial), Validation::Ok);
+        assert_eq!(RIFLE.validate(50.0, UnitSystem::Imperial), Validation::Ok);
+    }
+
+    #[test]
+    fn test_value_outside_soft_range_warns() {
+        assert_eq!(
+            RIFLE.validate(65.5, UnitSystem::Imperial),
+            Validation::Warning(
+                "Rifle weight of 65.50 lbs is outside the typical range of 5.00–50.00 lbs."
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            RIFLE.validate(65.5, UnitSystem::Metric),
+            Validation::Warning(
+                "Rifle weight of 29.71 kg is outside the typical range of 2.27–22.68 kg."
+                    .to_string()
+            )
+        );
+        assert!(matches!(
+            RIFLE.validate(2.0, UnitSystem::Imperial),
+            Validation::Warning(_)
+        ));
+    }
+
+    #[test]
+    fn test_messages_are_localized() {
+        i18n::set_language(i18n::Language::German);
+        let validation = RIFLE.validate(65.5, UnitSystem::Metric);
+        i18n::set_language(i18n::Language::English);
+        assert_eq!(
+            validation,
+            Validation::Warning(
+                "Gewehrgewicht von 29,71 kg liegt außerhalb des üblichen Bereichs von 2,27–22,68 kg."
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_non_positive_value_is_error() {
+        assert_eq!(
+            RIFLE.validate(0.0, UnitSystem::Imperial),
+            Validation::Error("Rifle weight must be greater than zero.".to_string())
+        );
+        assert!(RIFLE.validate(-3.0, UnitSystem::Imperial).is_error());
+        assert!(RIFLE.validate(f64::NAN, UnitSystem::Imperial).is_error());
+    }
+}
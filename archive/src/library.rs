@@ -0,0 +1,421 @@
+//! Named rifle and load setups, kept in local storage and exchanged as JSON
+//! files. The file format is documented in the README.
+
+use egui::{RichText, TextEdit, Ui};
+use serde::{Deserialize, Serialize};
+
+use crate::files::{self, FileOpener, FileType};
+use crate::i18n::{tr, tr_args};
+use crate::input::Validation;
+use crate::top::{MUZZLE_VELOCITY, PROJECTILE_WEIGHT, RIFLE_WEIGHT, TopApp, TopInputs};
+use crate::units::UnitSystem;
+
+/// Value of the `format` field identifying a setup file.
+const FORMAT: &str = "longrange-setups";
+/// Current version of the setup file format.
+const VERSION: u32 = 1;
+
+const EXPORT_FILE_NAME: &str = "longrange-setups.json";
+
+/// A named rifle and load. Numbers are stored in imperial units, which the
+/// field names spell out so exported files are unambiguous.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Setup {
+    pub name: String,
+    #[serde(default)]
+    pub rifle: String,
+    #[serde(default)]
+    pub load: String,
+    #[serde(default)]
+    pub notes: String,
+    pub projectile_weight_gr: f64,
+    pub muzzle_velocity_fps: f64,
+    pub rifle_weight_lbs: f64,
+}
+
+impl Setup {
+    /// Returns the first problem that would stop the setup being used.
+    fn validate(&self, units: UnitSystem) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err(tr("Setup names must not be empty.").to_string());
+        }
+        for (input, value) in [
+            (PROJECTILE_WEIGHT, self.projectile_weight_gr),
+            (MUZZLE_VELOCITY, self.muzzle_velocity_fps),
+            (RIFLE_WEIGHT, self.rifle_weight_lbs),
+        ] {
+            if let Validation::Error(message) = input.validate(value, units) {
+                return Err(tr_args("Setup \"{0}\": {1}", &[&self.name, &message]));
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the setup's values into `inputs`, keeping the rest as they are.
+    pub fn apply(&self, mut inputs: TopInputs) -> TopInputs {
+        inputs.projectile_weight = self.projectile_weight_gr;
+        inputs.muzzle_velocity = self.muzzle_velocity_fps;
+        inputs.rifle_weight = self.rifle_weight_lbs;
+        inputs
+    }
+
+    fn summary(&self, units: UnitSystem) -> String {
+        [
+            (PROJECTILE_WEIGHT, self.projectile_weight_gr),
+            (MUZZLE_VELOCITY, self.muzzle_velocity_fps),
+            (RIFLE_WEIGHT, self.rifle_weight_lbs),
+        ]
+        .map(|(input, value)| input.quantity.format(units, value))
+        .join(" · ")
+    }
+}
+
+/// On-disk layout of an exported library.
+#[derive(Serialize, Deserialize)]
+struct SetupFile {
+    format: String,
+    version: u32,
+    setups: Vec<Setup>,
+}
+
+/// The user's saved setups, in the order they were added.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Library {
+    setups: Vec<Setup>,
+}
+
+impl Library {
+    pub fn setups(&self) -> &[Setup] {
+        &self.setups
+    }
+
+    /// Adds `setup`, replacing any existing setup with the same name.
+    pub fn insert(&mut self, setup: Setup) {
+        match self.setups.iter_mut().find(|s| s.name == setup.name) {
+            Some(existing) => *existing = setup,
+            None => self.setups.push(setup),
+        }
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        if index < self.setups.len() {
+            self.setups.remove(index);
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let file = SetupFile {
+            format: FORMAT.to_string(),
+            version: VERSION,
+            setups: self.setups.clone(),
+        };
+        serde_json::to_string_pretty(&file).expect("setups always serialize")
+    }
+
+    /// Merges the setups from an exported file, replacing same-named ones.
+    /// Nothing is imported unless every setup in the file is valid. Returns
+    /// the number of setups imported.
+    pub fn import(&mut self, json: &str, units: UnitSystem) -> Result<usize, String> {
+        let file: SetupFile = serde_json::from_str(json)
+            .map_err(|err| tr_args("This is not a valid setup file: {0}", &[&err.to_string()]))?;
+        if file.format != FORMAT {
+            return Err(tr("This is not a setup file.").to_string());
+        }
+        if file.version > VERSION {
+            return Err(tr_args(
+                "Setup file version {0} needs a newer version of this app.",
+                &[&file.version.to_string()],
+            ));
+        }
+        for setup in &file.setups {
+            setup.validate(units)?;
+        }
+        let count = file.setups.len();
+        for setup in file.setups {
+            self.insert(setup);
+        }
+        Ok(count)
+    }
+}
+
+/// Window for saving, loading, importing and exporting setups.
+#[derive(Default)]
+pub struct LibraryWindow {
+    pub open: bool,
+    name: String,
+    rifle: String,
+    load: String,
+    notes: String,
+    status: Option<Result<String, String>>,
+    opener: FileOpener,
+}
+
+impl LibraryWindow {
+    pub fn ui(
+        &mut self,
+        ctx: &egui::Context,
+        library: &mut Library,
+        top: &mut TopApp,
+        units: UnitSystem,
+    ) {
+        if let Some(result) = self.opener.take() {
+            self.status = Some(
+                result
+                    .and_then(|json| library.import(&json, units))
+                    .map(|count| tr_args("Imported {0} setups.", &[&count.to_string()])),
+            );
+        }
+
+        let mut open = self.open;
+        egui::Window::new(tr("Setup library"))
+            .open(&mut open)
+            .default_width(360.0)
+            .show(ctx, |ui| {
+                self.render_save_form(ui, library, top);
+                ui.separator();
+                self.render_setups(ui, library, top, units);
+                ui.separator();
+                self.render_file_buttons(ui, library);
+            });
+        self.open = open;
+    }
+
+    fn render_save_form(&mut self, ui: &mut Ui, library: &mut Library, top: &TopApp) {
+        egui::Grid::new("setup_form").num_columns(2).show(ui, |ui| {
+            for (label, text) in [
+                (tr("Name:"), &mut self.name),
+                (tr("Rifle:"), &mut self.rifle),
+                (tr("Load:"), &mut self.load),
+            ] {
+                let label = ui.label(label);
+                ui.text_edit_singleline(text).labelled_by(label.id);
+                ui.end_row();
+            }
+            let label = ui.label(tr("Notes:"));
+            ui.add(TextEdit::multiline(&mut self.notes).desired_rows(2))
+                .labelled_by(label.id);
+            ui.end_row();
+        });
+
+        let name = self.name.trim();
+        if ui
+            .add_enabled(
+                !name.is_empty(),
+                egui::Button::new(tr("Save current inputs")),
+            )
+            .on_hover_text(tr("Replaces a saved setup with the same name."))
+            .clicked()
+        {
+            let inputs = top.inputs();
+            library.insert(Setup {
+                name: name.to_string(),
+                rifle: self.rifle.trim().to_string(),
+                load: self.load.trim().to_string(),
+                notes: self.notes.trim().to_string(),
+                projectile_weight_gr: inputs.projectile_weight,
+                muzzle_velocity_fps: inputs.muzzle_velocity,
+                rifle_weight_lbs: inputs.rifle_weight,
+            });
+            self.status = Some(Ok(tr_args("Saved {0}.", &[name])));
+        }
+    }
+
+    fn render_setups(
+        &mut self,
+        ui: &mut Ui,
+        library: &mut Library,
+        top: &mut TopApp,
+        units: UnitSystem,
+    ) {
+        if library.setups().is_empty() {
+            ui.weak(tr("No saved setups yet."));
+            return;
+        }
+
+        let mut remove = None;
+        egui::ScrollArea::vertical()
+            .max_height(240.0)
+            .show(ui, |ui| {
+                for (index, setup) in library.setups().iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(&setup.name).strong());
+                        let details = [setup.rifle.as_str(), setup.load.as_str()]
+                            .into_iter()
+                            .filter(|text| !text.is_empty())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        if !details.is_empty() {
+                            ui.label(details);
+                        }
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.button("🗑").on_hover_text(tr("Delete")).clicked() {
+                                remove = Some(index);
+                            }
+                            if ui.button(tr("Load")).clicked() {
+                                top.set_inputs(setup.apply(top.inputs()));
+                                self.name = setup.name.clone();
+                                self.rifle = setup.rifle.clone();
+                                self.load = setup.load.clone();
+                                self.notes = setup.notes.clone();
+                                self.status = Some(Ok(tr_args("Loaded {0}.", &[&setup.name])));
+                            }
+                        });
+                    });
+                    ui.weak(setup.summary(units));
+                    if !setup.notes.is_empty() {
+                        ui.label(RichText::new(&setup.notes).italics());
+                    }
+                    ui.add_space(4.0);
+                }
+            });
+        if let Some(index) = remove {
+            library.remove(index);
+        }
+    }
+
+    fn render_file_buttons(&mut self, ui: &mut Ui, library: &Library) {
+        ui.horizontal(|ui| {
+            if ui.button(tr("Import…")).clicked() {
+                self.opener.open(ui.ctx(), &[FileType::JSON]);
+            }
+            if ui
+                .add_enabled(
+                    !library.setups().is_empty(),
+                    egui::Button::new(tr("Export…")),
+                )
+                .clicked()
+            {
+                match files::save(
+                    EXPORT_FILE_NAME,
+                    library.to_json().as_bytes(),
+                    FileType::JSON,
+                ) {
+                    Ok(true) => {
+                        self.status = Some(Ok(tr_args(
+                            "Exported {0} setups.",
+                            &[&library.setups().len().to_string()],
+                        )))
+                    }
+                    Ok(false) => {}
+                    Err(err) => self.status = Some(Err(err)),
+                }
+            }
+        });
+
+        match &self.status {
+            Some(Ok(message)) => {
+                ui.label(message);
+            }
+            Some(Err(message)) => {
+                ui.colored_label(ui.visuals().error_fg_color, format!("⛔ {}", message));
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup(name: &str) -> Setup {
+        Setup {
+            name: name.to_string(),
+            rifle: "Tikka T3x".to_string(),
+            load: "175gr SMK".to_string(),
+            notes: String::new(),
+            projectile_weight_gr: 175.0,
+            muzzle_velocity_fps: 2600.0,
+            rifle_weight_lbs: 11.5,
+        }
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let mut library = Library::default();
+        library.insert(setup("Match"));
+        library.insert(setup("Practice"));
+
+        let mut imported = Library::default();
+        let count = imported
+            .import(&library.to_json(), UnitSystem::Imperial)
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(imported, library);
+    }
+
+    #[test]
+    fn test_insert_replaces_same_name() {
+        let mut library = Library::default();
+        library.insert(setup("Match"));
+        let mut updated = setup("Match");
+        updated.muzzle_velocity_fps = 2650.0;
+        library.insert(updated.clone());
+
+        assert_eq!(library.setups(), &[updated]);
+    }
+
+    #[test]
+    fn test_documented_example_imports() {
+        let json = r#"{
+            "format": "longrange-setups",
+            "version": 1,
+            "setups": [
+                {
+                    "name": "Match",
+                    "rifle": "Tikka T3x 6.5 Creedmoor",
+                    "load": "140gr ELD-M, H4350",
+                    "notes": "Zeroed at 100 yd",
+                    "projectile_weight_gr": 140.0,
+                    "muzzle_velocity_fps": 2710.0,
+                    "rifle_weight_lbs": 11.5
+                }
+            ]
+        }"#;
+        let mut library = Library::default();
+        assert_eq!(library.import(json, UnitSystem::Imperial), Ok(1));
+        assert_eq!(library.setups()[0].muzzle_velocity_fps, 2710.0);
+    }
+
+    #[test]
+    fn test_import_rejects_bad_files() {
+        let mut library = Library::default();
+        assert!(library.import("not json", UnitSystem::Imperial).is_err());
+        assert!(
+            library
+                .import(
+                    r#"{"format": "other", "version": 1, "setups": []}"#,
+                    UnitSystem::Imperial
+                )
+                .is_err()
+        );
+        assert!(
+            library
+                .import(
+                    r#"{"format": "longrange-setups", "version": 99, "setups": []}"#,
+                    UnitSystem::Imperial
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_import_is_all_or_nothing() {
+        let mut bad = setup("Broken");
+        bad.rifle_weight_lbs = 0.0;
+        let mut source = Library::default();
+        source.insert(setup("Match"));
+        source.insert(bad);
+
+        let mut library = Library::default();
+        let err = library
+            .import(&source.to_json(), UnitSystem::Imperial)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            "Setup \"Broken\": Rifle weight must be greater than zero."
+        );
+        assert!(library.setups().is_empty());
+    }
+}
@@ -13,9 +13,9 @@ fn main() -> eframe::Result {
     };
 
     eframe::run_native(
-        "TOP - Theory of Precision Calculator",
+        "r/longrange Calculators",
         native_options,
-        Box::new(|cc| Ok(Box::new(longrange::TopApp::new(cc)))),
+        Box::new(|cc| Ok(Box::new(longrange::LongRangeApp::new(cc)))),
     )
 }
 
@@ -42,7 +42,7 @@ fn main() {
             .start(
                 canvas,
                 eframe::WebOptions::default(),
-                Box::new(|cc| Ok(Box::new(longrange::TopApp::new(cc)))),
+                Box::new(|cc| Ok(Box::new(longrange::LongRangeApp::new(cc)))),
             )
             .await;
 
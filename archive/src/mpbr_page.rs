@@ -0,0 +1,364 @@
+//! The maximum point-blank range page: the zero that keeps a load inside a
+//! vital zone the longest, and a chart of its trajectory against the zone.
+
+use egui::{Color32, ComboBox, RichText, Ui};
+use egui_plot::{Legend, Line, LineStyle, MarkerShape, Plot, PlotPoints, Points};
+use serde::{Deserialize, Serialize};
+
+use crate::app::Settings;
+use crate::ballistics::{Load, PointBlankRange, Solver};
+use crate::ballistics_page::{BallisticsSetup, SIGHT_HEIGHT};
+use crate::i18n::{format_number, format_tick, tr, tr_args};
+use crate::input::{NumericInput, Validation};
+use crate::units::{Quantity, UnitSystem};
+
+pub const VITAL_ZONE: NumericInput = NumericInput {
+    label: "Vital zone",
+    name: "Vital zone diameter",
+    quantity: Quantity::Deflection,
+    soft_min: 2.0,
+    soft_max: 12.0,
+};
+
+/// How far the chart runs past the MPBR, as in the Elm app.
+const CHART_OVERRUN: f64 = 1.15;
+
+/// The load and vital zone, saved between sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MpbrSetup {
+    /// Index of the load on the ballistics page.
+    pub load: usize,
+    /// Diameter of the vital zone, in inches.
+    pub vital_zone: f64,
+}
+
+impl Default for MpbrSetup {
+    /// The Elm app's defaults.
+    fn default() -> Self {
+        Self {
+            load: 0,
+            vital_zone: 8.0,
+        }
+    }
+}
+
+impl MpbrSetup {
+    /// Short descriptions of what differs from `previous`, for the history
+    /// list. The load is described with the other loads, since it is an
+    /// index into them.
+    pub fn changes(&self, previous: &MpbrSetup, units: UnitSystem) -> Vec<String> {
+        let mut changes = Vec::new();
+        if self.vital_zone != previous.vital_zone {
+            changes.push(format!(
+                "{}: {}",
+                tr(VITAL_ZONE.name),
+                VITAL_ZONE.quantity.format(units, self.vital_zone)
+            ));
+        }
+        changes
+    }
+}
+
+/// What a result was solved for, so it is only solved again when they
+/// change.
+#[derive(Debug, Clone, PartialEq)]
+struct Inputs {
+    solver: Solver,
+    load: Load,
+    vital_zone: f64,
+}
+
+/// State of the MPBR page.
+pub struct MpbrApp {
+    setup: MpbrSetup,
+    result: Option<(Inputs, Result<PointBlankRange, String>)>,
+}
+
+impl MpbrApp {
+    pub fn new(setup: MpbrSetup) -> Self {
+        Self {
+            setup,
+            result: None,
+        }
+    }
+
+    pub fn setup(&self) -> &MpbrSetup {
+        &self.setup
+    }
+
+    pub fn set_setup(&mut self, setup: MpbrSetup) {
+        self.setup = setup;
+    }
+
+    fn validate(&self, ballistics: &BallisticsSetup, units: UnitSystem) -> Vec<Validation> {
+        let mut messages = vec![
+            VITAL_ZONE.validate(self.setup.vital_zone, units),
+            SIGHT_HEIGHT.validate(ballistics.rifle.sight_height, units),
+        ];
+        messages.retain(|message| *message != Validation::Ok);
+        messages
+    }
+
+    fn render_inputs(&mut self, ui: &mut Ui, loads: &[Load], units: UnitSystem) {
+        egui::Grid::new("mpbr_inputs")
+            .num_columns(3)
+            .show(ui, |ui| {
+                let label = ui.label(tr("Load:"));
+                ComboBox::from_id_salt("mpbr_load")
+                    .selected_text(&loads[self.setup.load].name)
+                    .show_ui(ui, |ui| {
+                        for (index, load) in loads.iter().enumerate() {
+                            ui.selectable_value(&mut self.setup.load, index, &load.name);
+                        }
+                    })
+                    .response
+                    .labelled_by(label.id);
+                ui.end_row();
+                VITAL_ZONE.show(ui, &mut self.setup.vital_zone, units);
+                ui.end_row();
+            });
+        ui.label(
+            RichText::new(tr(
+                "Sight height and air are taken from the ballistics page. The zero is found in still air on level ground.",
+            ))
+            .weak(),
+        );
+    }
+
+    /// The result for the current inputs, solving again only if they have
+    /// changed.
+    fn point_blank_range(&mut self, inputs: Inputs) -> &Result<PointBlankRange, String> {
+        if self.result.as_ref().map(|(solved, _)| solved) != Some(&inputs) {
+            let result = inputs
+                .solver
+                .point_blank_range(&inputs.load, inputs.vital_zone);
+            self.result = Some((inputs, result));
+        }
+        &self.result.as_ref().expect("solved above").1
+    }
+
+    fn render_results(ui: &mut Ui, mpbr: &PointBlankRange, units: UnitSystem) {
+        let range = |range| Quantity::Range.format(units, range);
+        egui::Grid::new("mpbr_results")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(tr("Optimal zero:"));
+                ui.label(RichText::new(range(mpbr.far_zero)).strong());
+                ui.end_row();
+                ui.label(format!("{}:", tr("Near zero")));
+                ui.label(range(mpbr.near_zero));
+                ui.end_row();
+                ui.label(format!("{}:", tr("Far zero")));
+                ui.label(range(mpbr.far_zero));
+                ui.end_row();
+                ui.label(tr("Maximum ordinate:"));
+                ui.label(format!(
+                    "{} @ {}",
+                    Quantity::Deflection.format(units, mpbr.max_ordinate),
+                    range(mpbr.max_ordinate_range)
+                ));
+                ui.end_row();
+                if mpbr.near_limit > 0.0 {
+                    ui.label(tr("Enters the vital zone:"));
+                    ui.label(range(mpbr.near_limit));
+                    ui.end_row();
+                }
+                ui.label(tr("MPBR:"));
+                ui.label(RichText::new(range(mpbr.far_limit)).strong());
+                ui.end_row();
+            });
+    }
+
+    fn render_chart(
+        ui: &mut Ui,
+        solver: &Solver,
+        load: &Load,
+        mpbr: &PointBlankRange,
+        settings: &Settings,
+    ) {
+        let units = settings.units;
+        let colors = settings.palette.plot_colors(ui.visuals().dark_mode);
+        let x = |range| Quantity::Range.display_value(units, range);
+        let y = |height| Quantity::Deflection.display_value(units, height);
+
+        let end = mpbr.far_limit * CHART_OVERRUN;
+        let ranges: Vec<f64> = (0..=end.ceil() as usize)
+            .map(|range| range as f64)
+            .collect();
+        let trajectory: Vec<[f64; 2]> = solver
+            .trajectory(load, mpbr.zero, &ranges)
+            .iter()
+            .map(|point| [x(point.range), y(point.drop)])
+            .collect();
+        let radius = y(mpbr.max_ordinate);
+        let level = |height| vec![[0.0, height], [x(end), height]];
+
+        let range_unit = Quantity::Range.unit(units);
+        let height_unit = Quantity::Deflection.unit(units);
+        Plot::new("mpbr_chart")
+            .legend(Legend::default())
+            .x_axis_label(tr_args("Range ({0})", &[range_unit]))
+            .y_axis_label(tr_args("Height ({0})", &[height_unit]))
+            .x_axis_formatter(|mark, _range| format_tick(mark.value, mark.step_size))
+            .y_axis_formatter(|mark, _range| format_tick(mark.value, mark.step_size))
+            .label_formatter(move |_name, value| {
+                format!(
+                    "{} {}\n{} {}",
+                    format_number(value.x, 0),
+                    range_unit,
+                    format_number(value.y, 1),
+                    height_unit
+                )
+            })
+            .show(ui, |plot_ui| {
+                plot_ui.line(
+                    Line::new(tr("Line of sight"), PlotPoints::new(level(0.0)))
+                        .color(Color32::GRAY)
+                        .style(LineStyle::Dashed { length: 6.0 }),
+                );
+                plot_ui.line(
+                    Line::new(tr("Vital zone"), PlotPoints::new(level(radius)))
+                        .color(colors.sigma2)
+                        .width(1.5)
+                        .style(LineStyle::Dashed { length: 10.0 }),
+                );
+                plot_ui.line(
+                    Line::new("", PlotPoints::new(level(-radius)))
+                        .color(colors.sigma2)
+                        .width(1.5)
+                        .style(LineStyle::Dashed { length: 10.0 }),
+                );
+                plot_ui.line(
+                    Line::new(tr("Trajectory"), PlotPoints::new(trajectory))
+                        .color(colors.expected)
+                        .width(2.5),
+                );
+                plot_ui.points(
+                    Points::new(
+                        "",
+                        vec![
+                            [x(mpbr.near_zero), 0.0],
+                            [x(mpbr.far_zero), 0.0],
+                            [x(mpbr.far_limit), -radius],
+                        ],
+                    )
+                    .color(colors.expected)
+                    .radius(5.0)
+                    .shape(MarkerShape::Circle)
+                    .filled(true),
+                );
+            });
+    }
+
+    pub fn ui(
+        &mut self,
+        ctx: &egui::Context,
+        settings: &Settings,
+        ballistics: &BallisticsSetup,
+        loads: &[Load],
+    ) {
+        let units = settings.units;
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading(tr("Maximum Point Blank Range"));
+            if loads.is_empty() {
+                ui.label(tr("Add a load on the ballistics page to find its MPBR."));
+                return;
+            }
+            self.setup.load = self.setup.load.min(loads.len() - 1);
+            self.render_inputs(ui, loads, units);
+            let validation = self.validate(ballistics, units);
+            for message in &validation {
+                match message {
+                    Validation::Error(text) => {
+                        ui.colored_label(ui.visuals().error_fg_color, format!("⛔ {}", text));
+                    }
+                    Validation::Warning(text) => {
+                        ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", text));
+                    }
+                    Validation::Ok => {}
+                }
+            }
+            ui.separator();
+            if validation.iter().any(Validation::is_error) {
+                ui.label(tr("Correct the inputs to see the MPBR."));
+                return;
+            }
+
+            let load = &loads[self.setup.load];
+            let solver = still_air(ballistics.solver());
+            let inputs = Inputs {
+                solver: solver.clone(),
+                load: load.clone(),
+                vital_zone: self.setup.vital_zone,
+            };
+            match self.point_blank_range(inputs) {
+                Ok(mpbr) => {
+                    let mpbr = *mpbr;
+                    Self::render_results(ui, &mpbr, units);
+                    ui.separator();
+                    Self::render_chart(ui, &solver, load, &mpbr, settings);
+                }
+                Err(message) => {
+                    let text = format!("⛔ {}: {}", load.name, message);
+                    ui.colored_label(ui.visuals().error_fg_color, text);
+                }
+            }
+        });
+    }
+}
+
+/// `solver` without wind, Earth rotation or look angle, fired in the air it
+/// is zeroed in: an MPBR is worked out for the rifle, not for a shot.
+fn still_air(solver: Solver) -> Solver {
+    Solver {
+        wind: Vec::new(),
+        earth_rotation: None,
+        look_angle: 0.0,
+        zero_atmosphere: None,
+        ..solver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ballistics_page::default_loads;
+
+    #[test]
+    fn test_still_air() {
+        let mut setup = BallisticsSetup {
+            look_angle: 20.0,
+            earth_rotation_enabled: true,
+            ..BallisticsSetup::default()
+        };
+        setup.rifle.sight_height = 2.5;
+        let solver = still_air(setup.solver());
+        assert!(solver.wind.is_empty());
+        assert_eq!(solver.earth_rotation, None);
+        assert_eq!(solver.look_angle, 0.0);
+        assert_eq!(solver.rifle.sight_height, 2.5);
+    }
+
+    #[test]
+    fn test_result_is_cached() {
+        let mut app = MpbrApp::new(MpbrSetup::default());
+        let inputs = Inputs {
+            solver: still_air(BallisticsSetup::default().solver()),
+            load: default_loads()[0].clone(),
+            vital_zone: 8.0,
+        };
+        let first = app.point_blank_range(inputs.clone()).clone().unwrap();
+        assert_eq!(app.point_blank_range(inputs.clone()), &Ok(first));
+
+        let smaller = app
+            .point_blank_range(Inputs {
+                vital_zone: 4.0,
+                ..inputs
+            })
+            .clone()
+            .unwrap();
+        assert!(smaller.far_limit < first.far_limit);
+    }
+}
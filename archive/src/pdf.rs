@@ -0,0 +1,662 @@
+//! A minimal PDF writer for printable reports.
+//!
+//! Only what the reports need is supported: text in the standard Helvetica
+//! fonts, lines, rectangles and simple charts. Standard fonts need no
+//! embedding, which keeps the output small and the writer free of
+//! dependencies, so it works the same in the native and web builds.
+//!
+//! Coordinates are in points (1/72 inch) measured from the top-left corner of
+//! the page; they are flipped to PDF's bottom-left origin when written.
+
+use std::io::Write as _;
+
+use egui::Color32;
+
+use crate::i18n::format_tick;
+
+/// A4 portrait, in points.
+pub const A4: (f32, f32) = (595.28, 841.89);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource(&self) -> &'static str {
+        match self {
+            Font::Regular => "/F1",
+            Font::Bold => "/F2",
+        }
+    }
+}
+
+/// Resource name of the Symbol font, used for Greek letters.
+const SYMBOL: &str = "/F3";
+
+/// Horizontal alignment of text relative to its `x` coordinate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// One line series in a [`Chart`], in data coordinates.
+pub struct Series<'a> {
+    pub label: Option<&'a str>,
+    pub points: &'a [[f64; 2]],
+    pub color: Color32,
+    pub width: f32,
+    pub dashed: bool,
+}
+
+/// A line chart with labelled axes and an optional legend.
+pub struct Chart<'a> {
+    pub x_label: &'a str,
+    pub y_label: &'a str,
+    pub series: Vec<Series<'a>>,
+    /// Highlighted points, drawn as filled circles.
+    pub markers: Vec<([f64; 2], Color32)>,
+}
+
+pub struct Page {
+    width: f32,
+    height: f32,
+    content: Vec<u8>,
+}
+
+impl Page {
+    pub fn new((width, height): (f32, f32)) -> Self {
+        Self {
+            width,
+            height,
+            content: Vec::new(),
+        }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Writes a single line of text with its baseline at `y`.
+    pub fn text(&mut self, x: f32, y: f32, size: f32, font: Font, align: Align, text: &str) {
+        let x = match align {
+            Align::Left => x,
+            Align::Center => x - text_width(text, font, size) / 2.0,
+            Align::Right => x - text_width(text, font, size),
+        };
+        let _ = write!(self.content, "0 g BT {:.2} {:.2} Td", x, self.height - y);
+        for (symbol, bytes) in encode(text) {
+            let resource = if symbol { SYMBOL } else { font.resource() };
+            let _ = write!(self.content, " {resource} {size:.2} Tf ");
+            write_string(&mut self.content, &bytes);
+            self.content.extend_from_slice(b" Tj");
+        }
+        self.content.extend_from_slice(b" ET\n");
+    }
+
+    /// Writes `text` word-wrapped to `max_width`, starting with the first
+    /// baseline at `y`. Returns the baseline below the last line.
+    pub fn paragraph(
+        &mut self,
+        x: f32,
+        y: f32,
+        max_width: f32,
+        size: f32,
+        font: Font,
+        text: &str,
+    ) -> f32 {
+        let leading = size * 1.3;
+        let mut y = y;
+        for line in wrap(text, font, size, max_width) {
+            self.text(x, y, size, font, Align::Left, &line);
+            y += leading;
+        }
+        y
+    }
+
+    /// Strokes a polyline.
+    pub fn line(&mut self, points: &[(f32, f32)], width: f32, color: Color32, dashed: bool) {
+        let Some(((x, y), rest)) = points.split_first() else {
+            return;
+        };
+        let dash = if dashed { "[3 2] 0 d" } else { "[] 0 d" };
+        let _ = write!(
+            self.content,
+            "{} RG {width:.2} w {dash} {x:.2} {:.2} m",
+            rgb(color),
+            self.height - y
+        );
+        for (x, y) in rest {
+            let _ = write!(self.content, " {x:.2} {:.2} l", self.height - y);
+        }
+        self.content.extend_from_slice(b" S\n");
+    }
+
+    pub fn rect(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        stroke: Option<Color32>,
+        fill: Option<Color32>,
+    ) {
+        let _ = write!(
+            self.content,
+            "{x:.2} {:.2} {width:.2} {height:.2} re",
+            self.height - y - height
+        );
+        self.paint(stroke, fill);
+    }
+
+    pub fn circle(&mut self, x: f32, y: f32, radius: f32, fill: Color32) {
+        // Four Bézier quarter arcs
+        const K: f32 = 0.552_284_8;
+        let y = self.height - y;
+        let r = radius;
+        let k = r * K;
+        let _ = write!(
+            self.content,
+            "{:.2} {y:.2} m \
+             {:.2} {:.2} {:.2} {:.2} {x:.2} {:.2} c \
+             {:.2} {:.2} {:.2} {:.2} {:.2} {y:.2} c \
+             {:.2} {:.2} {:.2} {:.2} {x:.2} {:.2} c \
+             {:.2} {:.2} {:.2} {:.2} {:.2} {y:.2} c",
+            x + r,
+            x + r,
+            y + k,
+            x + k,
+            y + r,
+            y + r,
+            x - k,
+            y + r,
+            x - r,
+            y + k,
+            x - r,
+            x - r,
+            y - k,
+            x - k,
+            y - r,
+            y - r,
+            x + k,
+            y - r,
+            x + r,
+            y - k,
+            x + r,
+        );
+        self.paint(None, Some(fill));
+    }
+
+    fn paint(&mut self, stroke: Option<Color32>, fill: Option<Color32>) {
+        if let Some(color) = stroke {
+            let _ = write!(self.content, " {} RG 0.5 w [] 0 d", rgb(color));
+        }
+        if let Some(color) = fill {
+            let _ = write!(self.content, " {} rg", rgb(color));
+        }
+        self.content.extend_from_slice(match (stroke, fill) {
+            (Some(_), Some(_)) => b" B\n",
+            (None, Some(_)) => b" f\n",
+            _ => b" S\n",
+        });
+    }
+
+    /// Draws `chart` with its plot area and axis labels inside the given box.
+    pub fn chart(&mut self, x: f32, y: f32, width: f32, height: f32, chart: &Chart<'_>) {
+        const TICK_SIZE: f32 = 7.5;
+        const LABEL_SIZE: f32 = 9.0;
+        let grid = Color32::from_gray(220);
+        let axis = Color32::from_gray(90);
+
+        let points = chart.series.iter().flat_map(|series| series.points.iter());
+        let (mut x_min, mut x_max, mut y_min, mut y_max) = points.fold(
+            (
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(x0, x1, y0, y1), p| (x0.min(p[0]), x1.max(p[0]), y0.min(p[1]), y1.max(p[1])),
+        );
+        if !x_min.is_finite() {
+            return;
+        }
+        if x_max <= x_min {
+            x_max = x_min + 1.0;
+        }
+        // Start the value axis at zero so values can be compared by eye
+        y_min = y_min.min(0.0);
+        if y_max <= y_min {
+            y_max = y_min + 1.0;
+        }
+        let x_step = nice_step(x_max - x_min);
+        let y_step = nice_step(y_max - y_min);
+        x_min = (x_min / x_step).floor() * x_step;
+        x_max = (x_max / x_step).ceil() * x_step;
+        y_min = (y_min / y_step).floor() * y_step;
+        y_max = (y_max / y_step).ceil() * y_step;
+
+        // Leave room for tick and axis labels
+        let left = x + 45.0;
+        let right = x + width - 5.0;
+        let top = y + 5.0;
+        let bottom = y + height - 35.0;
+        let to_page = |p: [f64; 2]| {
+            (
+                left + ((p[0] - x_min) / (x_max - x_min)) as f32 * (right - left),
+                bottom - ((p[1] - y_min) / (y_max - y_min)) as f32 * (bottom - top),
+            )
+        };
+
+        for tick in ticks(x_min, x_max, x_step) {
+            let (px, _) = to_page([tick, y_min]);
+            self.line(&[(px, top), (px, bottom)], 0.3, grid, false);
+            let label = format_tick(tick, x_step);
+            self.text(
+                px,
+                bottom + 10.0,
+                TICK_SIZE,
+                Font::Regular,
+                Align::Center,
+                &label,
+            );
+        }
+        for tick in ticks(y_min, y_max, y_step) {
+            let (_, py) = to_page([x_min, tick]);
+            self.line(&[(left, py), (right, py)], 0.3, grid, false);
+            let label = format_tick(tick, y_step);
+            self.text(
+                left - 4.0,
+                py + 2.5,
+                TICK_SIZE,
+                Font::Regular,
+                Align::Right,
+                &label,
+            );
+        }
+        self.rect(left, top, right - left, bottom - top, Some(axis), None);
+
+        self.text(
+            (left + right) / 2.0,
+            bottom + 26.0,
+            LABEL_SIZE,
+            Font::Regular,
+            Align::Center,
+            chart.x_label,
+        );
+        self.vertical_text(x + 8.0, (top + bottom) / 2.0, LABEL_SIZE, chart.y_label);
+
+        for series in &chart.series {
+            let points: Vec<_> = series.points.iter().map(|p| to_page(*p)).collect();
+            self.line(&points, series.width, series.color, series.dashed);
+        }
+        for (point, color) in &chart.markers {
+            let (px, py) = to_page(*point);
+            self.circle(px, py, 3.0, *color);
+        }
+
+        // Legend in the top-right corner of the plot area
+        let mut legend_y = top + 12.0;
+        for series in &chart.series {
+            let Some(label) = series.label else {
+                continue;
+            };
+            let label_width = text_width(label, Font::Regular, TICK_SIZE);
+            let sample_x = right - label_width - 30.0;
+            self.rect(
+                sample_x - 4.0,
+                legend_y - 8.0,
+                label_width + 34.0,
+                11.0,
+                None,
+                Some(Color32::WHITE),
+            );
+            self.line(
+                &[
+                    (sample_x, legend_y - 2.5),
+                    (sample_x + 20.0, legend_y - 2.5),
+                ],
+                series.width,
+                series.color,
+                series.dashed,
+            );
+            self.text(
+                sample_x + 24.0,
+                legend_y,
+                TICK_SIZE,
+                Font::Regular,
+                Align::Left,
+                label,
+            );
+            legend_y += 11.0;
+        }
+    }
+
+    /// Writes text rotated a quarter turn anticlockwise, centred on `y`.
+    fn vertical_text(&mut self, x: f32, y: f32, size: f32, text: &str) {
+        let y = self.height - y - text_width(text, Font::Regular, size) / 2.0;
+        let _ = write!(self.content, "0 g BT 0 1 -1 0 {x:.2} {y:.2} Tm");
+        for (symbol, bytes) in encode(text) {
+            let resource = if symbol {
+                SYMBOL
+            } else {
+                Font::Regular.resource()
+            };
+            let _ = write!(self.content, " {resource} {size:.2} Tf ");
+            write_string(&mut self.content, &bytes);
+            self.content.extend_from_slice(b" Tj");
+        }
+        self.content.extend_from_slice(b" ET\n");
+    }
+}
+
+/// A document of one or more pages.
+pub struct Document {
+    title: String,
+    pages: Vec<Page>,
+}
+
+impl Document {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            pages: Vec::new(),
+        }
+    }
+
+    pub fn add_page(&mut self, page: Page) {
+        self.pages.push(page);
+    }
+
+    /// Serializes the document as PDF 1.4.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // Fixed objects: 1 catalog, 2 page tree, 3-5 fonts, 6 info; then a
+        // page and its content stream for each page.
+        let page_id = |i: usize| 7 + 2 * i;
+        let mut objects: Vec<Vec<u8>> = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".into(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                (0..self.pages.len())
+                    .map(|i| format!("{} 0 R", page_id(i)))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                self.pages.len()
+            )
+            .into(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .into(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .into(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Symbol >>".into(),
+            format!(
+                "<< /Title {} /Producer (r/longrange Calculators) >>",
+                text_string(&self.title)
+            )
+            .into(),
+        ];
+        for (i, page) in self.pages.iter().enumerate() {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.2} {:.2}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R /F3 5 0 R >> >> \
+                 /Contents {} 0 R >>",
+                    page.width,
+                    page.height,
+                    page_id(i) + 1
+                )
+                .into(),
+            );
+            let mut stream = format!("<< /Length {} >>\nstream\n", page.content.len()).into_bytes();
+            stream.extend_from_slice(&page.content);
+            stream.extend_from_slice(b"endstream");
+            objects.push(stream);
+        }
+
+        let mut out = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            let _ = writeln!(out, "{} 0 obj", i + 1);
+            out.extend_from_slice(object);
+            out.extend_from_slice(b"\nendobj\n");
+        }
+        let xref = out.len();
+        let _ = write!(out, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(out, "{offset:010} 00000 n ");
+        }
+        let _ = write!(
+            out,
+            "trailer\n<< /Size {} /Root 1 0 R /Info 6 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        );
+        out
+    }
+}
+
+fn rgb(color: Color32) -> String {
+    format!(
+        "{:.3} {:.3} {:.3}",
+        color.r() as f32 / 255.0,
+        color.g() as f32 / 255.0,
+        color.b() as f32 / 255.0
+    )
+}
+
+/// Writes a PDF literal string.
+fn write_string(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.push(b'(');
+    for &byte in bytes {
+        if matches!(byte, b'(' | b')' | b'\\') {
+            out.push(b'\\');
+        }
+        out.push(byte);
+    }
+    out.push(b')');
+}
+
+/// A UTF-16 hex string for document metadata.
+fn text_string(text: &str) -> String {
+    let hex: String = text
+        .encode_utf16()
+        .map(|unit| format!("{unit:04X}"))
+        .collect();
+    format!("<FEFF{hex}>")
+}
+
+/// Splits text into runs of WinAnsi bytes and Symbol-font bytes (`true`).
+fn encode(text: &str) -> Vec<(bool, Vec<u8>)> {
+    let mut runs: Vec<(bool, Vec<u8>)> = Vec::new();
+    for c in text.chars() {
+        let (symbol, byte) = match symbol_code(c) {
+            Some(byte) => (true, byte),
+            None => (false, win_ansi_code(c)),
+        };
+        match runs.last_mut() {
+            Some((last, bytes)) if *last == symbol => bytes.push(byte),
+            _ => runs.push((symbol, vec![byte])),
+        }
+    }
+    runs
+}
+
+fn win_ansi_code(c: char) -> u8 {
+    match c {
+        ' '..='~' | '\u{A0}'..='\u{FF}' => c as u32 as u8,
+        '€' => 0x80,
+        '‚' => 0x82,
+        '„' => 0x84,
+        '…' => 0x85,
+        '‘' => 0x91,
+        '’' => 0x92,
+        '“' => 0x93,
+        '”' => 0x94,
+        '•' => 0x95,
+        '–' => 0x96,
+        '—' => 0x97,
+        '™' => 0x99,
+        _ => b'?',
+    }
+}
+
+fn symbol_code(c: char) -> Option<u8> {
+    match c {
+        'α' => Some(b'a'),
+        'β' => Some(b'b'),
+        'δ' => Some(b'd'),
+        'μ' => Some(b'm'),
+        'π' => Some(b'p'),
+        'σ' => Some(b's'),
+        'Δ' => Some(b'D'),
+        _ => None,
+    }
+}
+
+/// Advance widths of ASCII 32–126 in thousandths of the font size, from the
+/// Adobe font metrics.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667,
+    611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556,
+    278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// Width of `text` in points. Characters outside ASCII use an average width,
+/// which is close enough for layout.
+pub fn text_width(text: &str, font: Font, size: f32) -> f32 {
+    let widths = match font {
+        Font::Regular => &HELVETICA_WIDTHS,
+        Font::Bold => &HELVETICA_BOLD_WIDTHS,
+    };
+    let units: u32 = text
+        .chars()
+        .map(|c| match c {
+            ' '..='~' => widths[c as usize - 32] as u32,
+            '…' | '—' => 1000,
+            _ => 556,
+        })
+        .sum();
+    units as f32 * size / 1000.0
+}
+
+/// Breaks `text` into lines no wider than `max_width`. Words longer than a
+/// line are left on a line of their own.
+pub fn wrap(text: &str, font: Font, size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{line} {word}")
+        };
+        if text_width(&candidate, font, size) <= max_width || line.is_empty() {
+            line = candidate;
+        } else {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Tick spacing of 1, 2 or 5 times a power of ten giving about five ticks.
+fn nice_step(range: f64) -> f64 {
+    let raw = range / 5.0;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let step = match raw / magnitude {
+        r if r < 1.5 => 1.0,
+        r if r < 3.5 => 2.0,
+        r if r < 7.5 => 5.0,
+        _ => 10.0,
+    };
+    step * magnitude
+}
+
+fn ticks(min: f64, max: f64, step: f64) -> impl Iterator<Item = f64> {
+    let count = ((max - min) / step).round() as usize;
+    (0..=count).map(move |i| min + i as f64 * step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_structure() {
+        let mut page = Page::new(A4);
+        page.text(50.0, 50.0, 12.0, Font::Bold, Align::Left, "Report (draft)");
+        let mut document = Document::new("Report");
+        document.add_page(page);
+        let bytes = document.to_bytes();
+        let text = String::from_utf8_lossy(&bytes);
+
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("(Report \\(draft\\)) Tj"));
+
+        // Every xref entry points at the start of its object
+        let xref = text.rfind("\nxref\n").unwrap();
+        let entries: Vec<_> = text[xref..].lines().skip(4).take(8).collect();
+        assert_eq!(entries.len(), 8);
+        for (i, entry) in entries.iter().enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(text[offset..].starts_with(&format!("{} 0 obj", i + 1)));
+        }
+    }
+
+    #[test]
+    fn test_text_encoding() {
+        assert_eq!(
+            encode("Größe – 1σ"),
+            vec![
+                (
+                    false,
+                    vec![b'G', b'r', 0xF6, 0xDF, b'e', b' ', 0x96, b' ', b'1']
+                ),
+                (true, vec![b's']),
+            ]
+        );
+        assert_eq!(encode("→"), vec![(false, vec![b'?'])]);
+    }
+
+    #[test]
+    fn test_text_width() {
+        // "Hello" in Helvetica: 722 + 556 + 222 + 222 + 556
+        assert!((text_width("Hello", Font::Regular, 10.0) - 22.78).abs() < 1e-3);
+        assert!(text_width("Hello", Font::Bold, 10.0) > text_width("Hello", Font::Regular, 10.0));
+    }
+
+    #[test]
+    fn test_wrap_respects_width() {
+        let text = "Results from this tool are an estimate only, and rely on the use of a rifle";
+        let lines = wrap(text, Font::Regular, 10.0, 120.0);
+        assert!(lines.len() > 1);
+        for line in &lines {
+            assert!(text_width(line, Font::Regular, 10.0) <= 120.0, "{line}");
+        }
+        assert_eq!(lines.join(" "), text);
+    }
+
+    #[test]
+    fn test_nice_steps() {
+        assert_eq!(nice_step(45.0), 10.0);
+        assert_eq!(nice_step(4500.0), 1000.0);
+        assert_eq!(nice_step(2.0), 0.5);
+    }
+}
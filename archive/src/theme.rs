@@ -0,0 +1,205 @@
+use egui::{Color32, ThemePreference};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+/// Colour sets for plots. Each palette has a dark and a light variant so lines
+/// keep their contrast against either background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Palette {
+    #[default]
+    Classic,
+    /// Okabe-Ito colours, distinguishable with the common forms of colour blindness.
+    ColorBlindSafe,
+    HighContrast,
+}
+
+/// Colours for the series drawn on a plot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlotColors {
+    pub expected: Color32,
+    pub sigma1: Color32,
+    pub sigma2: Color32,
+}
+
+const OKABE_ITO_BLUE: Color32 = Color32::from_rgb(0, 114, 178);
+const OKABE_ITO_ORANGE: Color32 = Color32::from_rgb(230, 159, 0);
+const OKABE_ITO_VERMILLION: Color32 = Color32::from_rgb(213, 94, 0);
+const OKABE_ITO_SKY_BLUE: Color32 = Color32::from_rgb(86, 180, 233);
+const OKABE_ITO_PURPLE: Color32 = Color32::from_rgb(204, 121, 167);
+const OKABE_ITO_GREEN: Color32 = Color32::from_rgb(0, 158, 115);
+const OKABE_ITO_YELLOW: Color32 = Color32::from_rgb(240, 228, 66);
+
+/// The Elm app's colours for one line per load, in order.
+const ELM_LOAD_COLORS: [Color32; 7] = [
+    Color32::from_rgb(0x4a, 0x9e, 0xff),
+    Color32::from_rgb(0xff, 0x6b, 0x6b),
+    Color32::from_rgb(0x51, 0xcf, 0x66),
+    Color32::from_rgb(0xff, 0xd4, 0x3b),
+    Color32::from_rgb(0xff, 0x87, 0x87),
+    Color32::from_rgb(0x74, 0x8f, 0xfc),
+    Color32::from_rgb(0xff, 0x92, 0x2b),
+];
+
+impl Palette {
+    pub const ALL: [Palette; 3] = [
+        Palette::Classic,
+        Palette::ColorBlindSafe,
+        Palette::HighContrast,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Palette::Classic => tr("Classic"),
+            Palette::ColorBlindSafe => tr("Colour-blind safe"),
+            Palette::HighContrast => tr("High contrast"),
+        }
+    }
+
+    pub fn plot_colors(&self, dark_mode: bool) -> PlotColors {
+        match (self, dark_mode) {
+            (Palette::Classic, true) => PlotColors {
+                expected: Color32::from_rgb(30, 144, 255),
+                sigma1: Color32::from_rgba_unmultiplied(255, 200, 0, 100),
+                sigma2: Color32::from_rgba_unmultiplied(255, 100, 100, 80),
+            },
+            (Palette::Classic, false) => PlotColors {
+                expected: Color32::from_rgb(0, 100, 210),
+                sigma1: Color32::from_rgb(190, 130, 0),
+                sigma2: Color32::from_rgb(200, 50, 50),
+            },
+            (Palette::ColorBlindSafe, true) => PlotColors {
+                expected: OKABE_ITO_SKY_BLUE,
+                sigma1: OKABE_ITO_ORANGE,
+                sigma2: OKABE_ITO_PURPLE,
+            },
+            (Palette::ColorBlindSafe, false) => PlotColors {
+                expected: OKABE_ITO_BLUE,
+                sigma1: OKABE_ITO_ORANGE,
+                sigma2: OKABE_ITO_VERMILLION,
+            },
+            (Palette::HighContrast, true) => PlotColors {
+                expected: Color32::WHITE,
+                sigma1: Color32::YELLOW,
+                sigma2: Color32::from_rgb(0, 255, 255),
+            },
+            (Palette::HighContrast, false) => PlotColors {
+                expected: Color32::BLACK,
+                sigma1: Color32::from_rgb(0, 0, 200),
+                sigma2: Color32::from_rgb(200, 0, 0),
+            },
+        }
+    }
+
+    /// Colour of the line for the load at `index`, repeating after seven.
+    pub fn load_color(&self, index: usize, dark_mode: bool) -> Color32 {
+        let colors = match (self, dark_mode) {
+            (Palette::Classic, true) => ELM_LOAD_COLORS,
+            // Darker shades of the same hues, to read on white
+            (Palette::Classic, false) => [
+                Color32::from_rgb(0x19, 0x71, 0xc2),
+                Color32::from_rgb(0xe0, 0x31, 0x31),
+                Color32::from_rgb(0x2f, 0x9e, 0x44),
+                Color32::from_rgb(0xe6, 0x77, 0x00),
+                Color32::from_rgb(0xc2, 0x25, 0x5c),
+                Color32::from_rgb(0x42, 0x63, 0xeb),
+                Color32::from_rgb(0xd9, 0x48, 0x0f),
+            ],
+            (Palette::ColorBlindSafe, true) => [
+                OKABE_ITO_SKY_BLUE,
+                OKABE_ITO_ORANGE,
+                OKABE_ITO_GREEN,
+                OKABE_ITO_YELLOW,
+                OKABE_ITO_PURPLE,
+                OKABE_ITO_VERMILLION,
+                OKABE_ITO_BLUE,
+            ],
+            (Palette::ColorBlindSafe, false) => [
+                OKABE_ITO_BLUE,
+                OKABE_ITO_ORANGE,
+                OKABE_ITO_GREEN,
+                OKABE_ITO_VERMILLION,
+                OKABE_ITO_PURPLE,
+                OKABE_ITO_SKY_BLUE,
+                Color32::BLACK,
+            ],
+            (Palette::HighContrast, true) => [
+                Color32::WHITE,
+                Color32::YELLOW,
+                Color32::from_rgb(0, 255, 255),
+                Color32::from_rgb(255, 0, 255),
+                Color32::from_rgb(0, 255, 0),
+                Color32::from_rgb(255, 140, 0),
+                Color32::from_rgb(255, 100, 100),
+            ],
+            (Palette::HighContrast, false) => [
+                Color32::BLACK,
+                Color32::from_rgb(0, 0, 200),
+                Color32::from_rgb(200, 0, 0),
+                Color32::from_rgb(0, 130, 0),
+                Color32::from_rgb(170, 0, 170),
+                Color32::from_rgb(150, 80, 0),
+                Color32::from_rgb(0, 120, 130),
+            ],
+        };
+        colors[index % colors.len()]
+    }
+}
+
+pub fn theme_label(theme: ThemePreference) -> &'static str {
+    match theme {
+        ThemePreference::System => tr("System"),
+        ThemePreference::Light => tr("Light"),
+        ThemePreference::Dark => tr("Dark"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_light_variants_are_opaque() {
+        for palette in Palette::ALL {
+            let colors = palette.plot_colors(false);
+            for color in [colors.expected, colors.sigma1, colors.sigma2] {
+                assert!(color.is_opaque(), "{:?}", palette);
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_colors() {
+        // The classic dark palette is the Elm chart's, repeating after seven
+        let classic = Palette::Classic;
+        assert_eq!(
+            classic.load_color(0, true),
+            Color32::from_rgb(0x4a, 0x9e, 0xff)
+        );
+        assert_eq!(classic.load_color(7, true), classic.load_color(0, true));
+
+        for palette in Palette::ALL {
+            for dark_mode in [true, false] {
+                let colors: Vec<_> = (0..7)
+                    .map(|index| palette.load_color(index, dark_mode))
+                    .collect();
+                for (index, color) in colors.iter().enumerate() {
+                    assert!(color.is_opaque());
+                    assert!(!colors[..index].contains(color), "{palette:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_plot_series_are_distinct() {
+        for palette in Palette::ALL {
+            for dark_mode in [true, false] {
+                let colors = palette.plot_colors(dark_mode);
+                assert_ne!(colors.expected, colors.sigma1);
+                assert_ne!(colors.expected, colors.sigma2);
+                assert_ne!(colors.sigma1, colors.sigma2);
+            }
+        }
+    }
+}
@@ -0,0 +1,1037 @@
+use egui::{ComboBox, Key, KeyboardShortcut, Modifiers, RichText, Ui, WidgetInfo, WidgetType};
+use egui_flex::{Flex, FlexItem};
+use egui_plot::{Legend, Line, LineStyle, Plot, PlotPoints, Points};
+
+use crate::app::Settings;
+use crate::files::{self, FileType};
+use crate::i18n::{format_number, format_tick, tr, tr_args};
+use crate::input::{NumericInput, Validation};
+use crate::pdf::{self, Align, Chart, Document, Font, Page, Series};
+use crate::units::{Quantity, UnitSystem};
+
+type PlotData = (
+    Vec<[f64; 2]>,
+    Vec<[f64; 2]>,
+    Vec<[f64; 2]>,
+    Vec<[f64; 2]>,
+    Vec<[f64; 2]>,
+    String,
+    String,
+);
+
+const DISCLAIMER: [&str; 4] = [
+    "Welcome to the r/longrange TOP Gun calculator.",
+    "This calculator is based on the TOP (Theory of Precision) Gun formula published by Applied Ballistics in Modern Advancements in Long Range Shooting, Vol 3.",
+    "This tool is provided for free by the moderator team of r/Longrange to help answer shooter questions and manage expectations for the precision (group size) of a given rifle.",
+    "Results from this tool are an estimate only, and rely on the use of a rifle and optic in good condition with no mechanical issues (scope problems, loose screws, etc) and commercial match grade ammo or comparable hand loads.",
+];
+
+const COMMUNITY_URL: &str = "https://reddit.com/r/longrange";
+const SPREADSHEET_URL: &str =
+    "https://docs.google.com/spreadsheets/d/1S0DMLcmj-Jvag5NwKrVAQUR2eOwpWTozy28jTVe998g/";
+
+pub const PROJECTILE_WEIGHT: NumericInput = NumericInput {
+    label: "Projectile",
+    name: "Projectile weight",
+    quantity: Quantity::BulletWeight,
+    soft_min: 50.0,
+    soft_max: 500.0,
+};
+
+pub const MUZZLE_VELOCITY: NumericInput = NumericInput {
+    label: "Velocity",
+    name: "Muzzle velocity",
+    quantity: Quantity::Velocity,
+    soft_min: 500.0,
+    soft_max: 5000.0,
+};
+
+pub const RIFLE_WEIGHT: NumericInput = NumericInput {
+    label: "Rifle",
+    name: "Rifle weight",
+    quantity: Quantity::RifleWeight,
+    soft_min: 5.0,
+    soft_max: 50.0,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GraphVariable {
+    RifleWeight,
+    Velocity,
+    ProjectileWeight,
+}
+
+impl GraphVariable {
+    const ALL: [GraphVariable; 3] = [
+        GraphVariable::RifleWeight,
+        GraphVariable::Velocity,
+        GraphVariable::ProjectileWeight,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            GraphVariable::RifleWeight => tr("Rifle Weight"),
+            GraphVariable::Velocity => tr("Velocity"),
+            GraphVariable::ProjectileWeight => tr("Projectile Weight"),
+        }
+    }
+
+    /// The input plotted along the x axis.
+    fn input(&self) -> NumericInput {
+        match self {
+            GraphVariable::RifleWeight => RIFLE_WEIGHT,
+            GraphVariable::Velocity => MUZZLE_VELOCITY,
+            GraphVariable::ProjectileWeight => PROJECTILE_WEIGHT,
+        }
+    }
+
+    /// Alt+1, Alt+2 and Alt+3 select the graph variable from anywhere.
+    fn shortcut(&self) -> KeyboardShortcut {
+        let key = match self {
+            GraphVariable::RifleWeight => Key::Num1,
+            GraphVariable::Velocity => Key::Num2,
+            GraphVariable::ProjectileWeight => Key::Num3,
+        };
+        KeyboardShortcut::new(Modifiers::ALT, key)
+    }
+}
+
+/// The user-editable state of the TOP calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TopInputs {
+    pub projectile_weight: f64,
+    pub muzzle_velocity: f64,
+    pub rifle_weight: f64,
+    graph_variable: GraphVariable,
+}
+
+impl TopInputs {
+    /// Short descriptions of what differs from `previous`, for the history list.
+    pub fn changes(&self, previous: &TopInputs, units: UnitSystem) -> Vec<String> {
+        let mut changes = Vec::new();
+        for (input, value, old) in [
+            (
+                PROJECTILE_WEIGHT,
+                self.projectile_weight,
+                previous.projectile_weight,
+            ),
+            (
+                MUZZLE_VELOCITY,
+                self.muzzle_velocity,
+                previous.muzzle_velocity,
+            ),
+            (RIFLE_WEIGHT, self.rifle_weight, previous.rifle_weight),
+        ] {
+            if value != old {
+                changes.push(format!(
+                    "{}: {}",
+                    tr(input.name),
+                    input.quantity.format(units, value)
+                ));
+            }
+        }
+        if self.graph_variable != previous.graph_variable {
+            changes.push(format!("{} {}", tr("Graph:"), self.graph_variable.label()));
+        }
+        changes
+    }
+}
+
+pub struct TopApp {
+    projectile_weight: f64,
+    muzzle_velocity: f64,
+    rifle_weight: f64,
+    graph_variable: GraphVariable,
+    hover_point: Option<[f64; 2]>,
+    report_error: Option<String>,
+}
+
+impl Default for TopApp {
+    fn default() -> Self {
+        Self {
+            projectile_weight: 168.0,
+            muzzle_velocity: 2650.0,
+            rifle_weight: 12.0,
+            graph_variable: GraphVariable::RifleWeight,
+            hover_point: None,
+            report_error: None,
+        }
+    }
+}
+
+impl TopApp {
+    pub fn inputs(&self) -> TopInputs {
+        TopInputs {
+            projectile_weight: self.projectile_weight,
+            muzzle_velocity: self.muzzle_velocity,
+            rifle_weight: self.rifle_weight,
+            graph_variable: self.graph_variable,
+        }
+    }
+
+    pub fn set_inputs(&mut self, inputs: TopInputs) {
+        self.projectile_weight = inputs.projectile_weight;
+        self.muzzle_velocity = inputs.muzzle_velocity;
+        self.rifle_weight = inputs.rifle_weight;
+        self.graph_variable = inputs.graph_variable;
+    }
+
+    fn calculate_kinetic_energy(grain_weight: f64, velocity_fps: f64) -> f64 {
+        (grain_weight * velocity_fps.powi(2)) / 450_436.0
+    }
+
+    fn calculate_moa(kinetic_energy: f64, rifle_weight: f64) -> f64 {
+        kinetic_energy / 200.0 / rifle_weight
+    }
+
+    fn calculate_value_for_1moa(&self) -> (f64, Quantity) {
+        let ke = Self::calculate_kinetic_energy(self.projectile_weight, self.muzzle_velocity);
+        match self.graph_variable {
+            GraphVariable::RifleWeight => (ke / 200.0, Quantity::RifleWeight),
+            GraphVariable::Velocity => {
+                let target_ke = 200.0 * self.rifle_weight;
+                (
+                    (target_ke * 450_436.0 / self.projectile_weight).sqrt(),
+                    Quantity::Velocity,
+                )
+            }
+            GraphVariable::ProjectileWeight => {
+                let target_ke = 200.0 * self.rifle_weight;
+                (
+                    target_ke * 450_436.0 / (self.muzzle_velocity * self.muzzle_velocity),
+                    Quantity::BulletWeight,
+                )
+            }
+        }
+    }
+
+    fn render_config_bar(&mut self, ui: &mut Ui, settings: &Settings) {
+        let units = settings.units;
+        let available_width = ui.available_width();
+        let is_narrow = available_width < 800.0;
+
+        if is_narrow {
+            // Stack vertically on narrow screens
+            ui.vertical(|ui| {
+                // Graph selection
+                self.render_graph_selector(ui);
+
+                ui.add_space(5.0);
+                ui.separator();
+                ui.add_space(5.0);
+
+                // Parameters section - stack vertically on mobile
+                ui.vertical(|ui| {
+                    self.render_parameters_stacked(ui, units);
+                    self.render_report_button(ui, settings);
+                });
+            });
+        } else {
+            // Horizontal layout for wider screens
+            Flex::horizontal().show(ui, |flex| {
+                // Graph selection
+                flex.add_ui(FlexItem::new(), |ui: &mut Ui| {
+                    self.render_graph_selector(ui);
+                });
+
+                flex.add_ui(FlexItem::new().basis(10.0).grow(0.0), |ui: &mut Ui| {
+                    ui.separator();
+                });
+
+                // Parameters section
+                flex.add_ui(FlexItem::new(), |ui: &mut Ui| {
+                    ui.horizontal(|ui| {
+                        self.render_parameters_inline(ui, units);
+                        self.render_report_button(ui, settings);
+                    });
+                });
+            });
+        }
+    }
+
+    fn render_graph_selector(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            let label = ui.label(tr("Graph:"));
+            ComboBox::from_id_salt("graph_variable")
+                .selected_text(self.graph_variable.label())
+                .show_ui(ui, |ui| {
+                    for variable in GraphVariable::ALL {
+                        ui.selectable_value(&mut self.graph_variable, variable, variable.label())
+                            .on_hover_text(ui.ctx().format_shortcut(&variable.shortcut()));
+                    }
+                })
+                .response
+                .labelled_by(label.id);
+        });
+    }
+
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        for variable in GraphVariable::ALL {
+            if ctx.input_mut(|i| i.consume_shortcut(&variable.shortcut())) {
+                self.graph_variable = variable;
+            }
+        }
+    }
+
+    fn current_value(&self, variable: GraphVariable) -> f64 {
+        match variable {
+            GraphVariable::RifleWeight => self.rifle_weight,
+            GraphVariable::Velocity => self.muzzle_velocity,
+            GraphVariable::ProjectileWeight => self.projectile_weight,
+        }
+    }
+
+    /// Text alternative for the plot, read out by screen readers.
+    fn plot_summary(&self, units: UnitSystem) -> String {
+        let (x_label, y_label) = self.axis_labels(units);
+        let mut summary = tr_args("{0} versus {1}.", &[&y_label, &x_label]);
+
+        let input = self.graph_variable.input();
+        let current = self.current_value(self.graph_variable);
+        if !input.validate(current, units).is_error() {
+            let ke = Self::calculate_kinetic_energy(self.projectile_weight, self.muzzle_velocity);
+            let moa = Self::calculate_moa(ke, self.rifle_weight);
+            summary.push(' ');
+            summary.push_str(&tr_args(
+                "At {0} {1}: expected {2} MOA, 1σ band {3}–{4} MOA ({5} MOA wide), 2σ band {6}–{7} MOA ({8} MOA wide).",
+                &[
+                    tr(input.name),
+                    &input.quantity.format(units, current),
+                    &format_number(moa, 2),
+                    &format_number(moa * 0.85, 2),
+                    &format_number(moa * 1.15, 2),
+                    &format_number(moa * 0.30, 2),
+                    &format_number(moa * 0.70, 2),
+                    &format_number(moa * 1.30, 2),
+                    &format_number(moa * 0.60, 2),
+                ],
+            ));
+        }
+
+        summary.push(' ');
+        summary.push_str(&self.value_for_1moa_text(units));
+        summary.push('.');
+        summary
+    }
+
+    /// The two inputs shown for the current graph variable; the graphed
+    /// variable itself is on the x axis.
+    fn visible_inputs(&mut self) -> [(NumericInput, &mut f64); 2] {
+        match self.graph_variable {
+            GraphVariable::RifleWeight => [
+                (PROJECTILE_WEIGHT, &mut self.projectile_weight),
+                (MUZZLE_VELOCITY, &mut self.muzzle_velocity),
+            ],
+            GraphVariable::Velocity => [
+                (PROJECTILE_WEIGHT, &mut self.projectile_weight),
+                (RIFLE_WEIGHT, &mut self.rifle_weight),
+            ],
+            GraphVariable::ProjectileWeight => [
+                (MUZZLE_VELOCITY, &mut self.muzzle_velocity),
+                (RIFLE_WEIGHT, &mut self.rifle_weight),
+            ],
+        }
+    }
+
+    /// Warnings and errors for the inputs used by the current graph.
+    fn validation_messages(&self, units: UnitSystem) -> Vec<Validation> {
+        let inputs = match self.graph_variable {
+            GraphVariable::RifleWeight => [
+                (PROJECTILE_WEIGHT, self.projectile_weight),
+                (MUZZLE_VELOCITY, self.muzzle_velocity),
+            ],
+            GraphVariable::Velocity => [
+                (PROJECTILE_WEIGHT, self.projectile_weight),
+                (RIFLE_WEIGHT, self.rifle_weight),
+            ],
+            GraphVariable::ProjectileWeight => [
+                (MUZZLE_VELOCITY, self.muzzle_velocity),
+                (RIFLE_WEIGHT, self.rifle_weight),
+            ],
+        };
+        inputs
+            .iter()
+            .map(|(input, value)| input.validate(*value, units))
+            .filter(|validation| *validation != Validation::Ok)
+            .collect()
+    }
+
+    fn has_errors(&self) -> bool {
+        // Errors do not depend on the display units
+        self.validation_messages(UnitSystem::Imperial)
+            .iter()
+            .any(Validation::is_error)
+    }
+
+    fn value_for_1moa_text(&self, units: UnitSystem) -> String {
+        if self.has_errors() {
+            return tr_args("1 MOA @ {0}", &["—"]);
+        }
+        let (value, quantity) = self.calculate_value_for_1moa();
+        tr_args("1 MOA @ {0}", &[&quantity.format(units, value)])
+    }
+
+    fn render_parameters_inline(&mut self, ui: &mut Ui, units: UnitSystem) {
+        for (input, value) in self.visible_inputs() {
+            input.show(ui, value, units);
+        }
+
+        ui.label(self.value_for_1moa_text(units));
+    }
+
+    fn render_parameters_stacked(&mut self, ui: &mut Ui, units: UnitSystem) {
+        for (input, value) in self.visible_inputs() {
+            ui.horizontal(|ui| {
+                input.show(ui, value, units);
+            });
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(self.value_for_1moa_text(units));
+        });
+    }
+
+    fn render_validation(&self, ui: &mut Ui, units: UnitSystem) {
+        for validation in self.validation_messages(units) {
+            match validation {
+                Validation::Warning(message) => {
+                    ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", message));
+                }
+                Validation::Error(message) => {
+                    ui.colored_label(ui.visuals().error_fg_color, format!("⛔ {}", message));
+                }
+                Validation::Ok => {}
+            }
+        }
+    }
+
+    fn axis_labels(&self, units: UnitSystem) -> (String, String) {
+        let x_label = match self.graph_variable {
+            GraphVariable::RifleWeight => {
+                tr_args("Rifle Weight ({0})", &[Quantity::RifleWeight.unit(units)])
+            }
+            GraphVariable::Velocity => {
+                tr_args("Muzzle Velocity ({0})", &[Quantity::Velocity.unit(units)])
+            }
+            GraphVariable::ProjectileWeight => tr_args(
+                "Projectile Weight ({0})",
+                &[Quantity::BulletWeight.unit(units)],
+            ),
+        };
+        (x_label, tr("5-Round Group Size (MOA)").to_string())
+    }
+
+    fn generate_plot_data(&self, units: UnitSystem) -> PlotData {
+        let num_points = 200;
+        let mut expected_line = Vec::new();
+        let mut sd1_upper = Vec::new();
+        let mut sd1_lower = Vec::new();
+        let mut sd2_upper = Vec::new();
+        let mut sd2_lower = Vec::new();
+
+        match self.graph_variable {
+            GraphVariable::RifleWeight => {
+                // Graph rifle weight
+                for i in 0..num_points {
+                    let rifle_weight = RIFLE_WEIGHT.soft_min
+                        + ((RIFLE_WEIGHT.soft_max - RIFLE_WEIGHT.soft_min) * i as f64)
+                            / (num_points - 1) as f64;
+                    let ke = Self::calculate_kinetic_energy(
+                        self.projectile_weight,
+                        self.muzzle_velocity,
+                    );
+                    let moa = Self::calculate_moa(ke, rifle_weight);
+
+                    let x = RIFLE_WEIGHT.quantity.display_value(units, rifle_weight);
+                    expected_line.push([x, moa]);
+                    sd1_upper.push([x, moa * 1.15]);
+                    sd1_lower.push([x, moa * 0.85]);
+                    sd2_upper.push([x, moa * 1.30]);
+                    sd2_lower.push([x, moa * 0.70]);
+                }
+            }
+            GraphVariable::Velocity => {
+                // Graph velocity
+                for i in 0..num_points {
+                    let velocity = MUZZLE_VELOCITY.soft_min
+                        + ((MUZZLE_VELOCITY.soft_max - MUZZLE_VELOCITY.soft_min) * i as f64)
+                            / (num_points - 1) as f64;
+                    let ke = Self::calculate_kinetic_energy(self.projectile_weight, velocity);
+                    let moa = Self::calculate_moa(ke, self.rifle_weight);
+
+                    let x = MUZZLE_VELOCITY.quantity.display_value(units, velocity);
+                    expected_line.push([x, moa]);
+                    sd1_upper.push([x, moa * 1.15]);
+                    sd1_lower.push([x, moa * 0.85]);
+                    sd2_upper.push([x, moa * 1.30]);
+                    sd2_lower.push([x, moa * 0.70]);
+                }
+            }
+            GraphVariable::ProjectileWeight => {
+                // Graph projectile weight
+                for i in 0..num_points {
+                    let projectile_weight = PROJECTILE_WEIGHT.soft_min
+                        + ((PROJECTILE_WEIGHT.soft_max - PROJECTILE_WEIGHT.soft_min) * i as f64)
+                            / (num_points - 1) as f64;
+                    let ke =
+                        Self::calculate_kinetic_energy(projectile_weight, self.muzzle_velocity);
+                    let moa = Self::calculate_moa(ke, self.rifle_weight);
+
+                    let x = PROJECTILE_WEIGHT
+                        .quantity
+                        .display_value(units, projectile_weight);
+                    expected_line.push([x, moa]);
+                    sd1_upper.push([x, moa * 1.15]);
+                    sd1_lower.push([x, moa * 0.85]);
+                    sd2_upper.push([x, moa * 1.30]);
+                    sd2_lower.push([x, moa * 0.70]);
+                }
+            }
+        }
+
+        let (x_label, y_label) = self.axis_labels(units);
+        (
+            expected_line,
+            sd1_upper,
+            sd1_lower,
+            sd2_upper,
+            sd2_lower,
+            x_label,
+            y_label,
+        )
+    }
+
+    fn render_plot(&mut self, ui: &mut Ui, settings: &Settings) {
+        let units = settings.units;
+        let colors = settings.palette.plot_colors(ui.visuals().dark_mode);
+        let (expected_line, sd1_upper, sd1_lower, sd2_upper, sd2_lower, x_label, y_label) =
+            self.generate_plot_data(units);
+
+        // Determine units for tooltip
+        let x_unit = self.graph_variable.input().quantity.unit(units);
+        let y_unit = "MOA";
+
+        let summary = self.plot_summary(units);
+        let hover_point = self.hover_point;
+        Plot::new("precision_plot")
+            .legend(Legend::default())
+            .x_axis_label(x_label)
+            .y_axis_label(y_label)
+            .x_axis_formatter(|mark, _range| format_tick(mark.value, mark.step_size))
+            .y_axis_formatter(|mark, _range| format_tick(mark.value, mark.step_size))
+            .label_formatter(move |_name, _value| {
+                if let Some(point) = hover_point {
+                    format!(
+                        "{} {}\n{} {}",
+                        format_number(point[0], 1),
+                        x_unit,
+                        format_number(point[1], 3),
+                        y_unit
+                    )
+                } else {
+                    String::new()
+                }
+            })
+            .allow_zoom(true)
+            .allow_drag(true)
+            .allow_scroll(true)
+            .show(ui, |plot_ui| {
+                // 2σ (95% confidence) - upper and lower bounds
+                plot_ui.line(
+                    Line::new(tr("2σ (95%)"), PlotPoints::new(sd2_upper))
+                        .color(colors.sigma2)
+                        .width(1.5)
+                        .style(LineStyle::Dotted { spacing: 10.0 }),
+                );
+                plot_ui.line(
+                    Line::new("", PlotPoints::new(sd2_lower))
+                        .color(colors.sigma2)
+                        .width(1.5)
+                        .style(LineStyle::Dotted { spacing: 10.0 }),
+                );
+
+                // 1σ (68% confidence) - upper and lower bounds
+                plot_ui.line(
+                    Line::new(tr("1σ (68%)"), PlotPoints::new(sd1_upper))
+                        .color(colors.sigma1)
+                        .width(1.5)
+                        .style(LineStyle::Dotted { spacing: 10.0 }),
+                );
+                plot_ui.line(
+                    Line::new("", PlotPoints::new(sd1_lower))
+                        .color(colors.sigma1)
+                        .width(1.5)
+                        .style(LineStyle::Dotted { spacing: 10.0 }),
+                );
+
+                // Expected line - draw last (on top)
+                plot_ui.line(
+                    Line::new(
+                        tr("Expected Precision"),
+                        PlotPoints::new(expected_line.clone()),
+                    )
+                    .color(colors.expected)
+                    .width(2.5),
+                );
+
+                // Draw marker on expected precision line at cursor position
+                if let Some(hover_pos) = plot_ui.pointer_coordinate() {
+                    // Find the closest point on the expected line to the cursor X position
+                    if let Some(point_on_line) = expected_line.iter().min_by(|a, b| {
+                        (a[0] - hover_pos.x)
+                            .abs()
+                            .partial_cmp(&(b[0] - hover_pos.x).abs())
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    }) {
+                        // Store the point for the label formatter
+                        self.hover_point = Some(*point_on_line);
+
+                        // Draw a bold marker at this point
+                        plot_ui.points(
+                            Points::new("", vec![[point_on_line[0], point_on_line[1]]])
+                                .color(colors.expected)
+                                .radius(6.0)
+                                .shape(egui_plot::MarkerShape::Circle)
+                                .filled(true),
+                        );
+                    }
+                } else {
+                    self.hover_point = None;
+                }
+            })
+            .response
+            .widget_info(|| WidgetInfo::labeled(WidgetType::Other, true, &summary));
+    }
+
+    fn render_footer(&self, ui: &mut Ui) {
+        let available_width = ui.available_width();
+        let is_narrow = available_width < 600.0;
+
+        ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 2.0;
+            for paragraph in DISCLAIMER {
+                ui.label(tr(paragraph));
+            }
+            ui.add_space(5.0);
+
+            if is_narrow {
+                // Stack vertically on narrow screens
+                ui.vertical(|ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(tr("Community:")).strong());
+                        ui.hyperlink_to("reddit/r/longrange", COMMUNITY_URL);
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(tr("Original:")).strong());
+                        ui.hyperlink_to(tr("TOP Gun Calculator Spreadsheet"), SPREADSHEET_URL);
+                    });
+                });
+            } else {
+                // Horizontal layout for wider screens
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(tr("Community:")).strong());
+                    ui.hyperlink_to("reddit/r/longrange", COMMUNITY_URL);
+                    ui.label("|");
+                    ui.label(RichText::new(tr("Original:")).strong());
+                    ui.hyperlink_to(tr("TOP Gun Calculator Spreadsheet"), SPREADSHEET_URL);
+                });
+            }
+        });
+    }
+}
+
+impl TopApp {
+    fn render_report_button(&mut self, ui: &mut Ui, settings: &Settings) {
+        let button = ui
+            .add_enabled(self.can_report(), egui::Button::new(tr("📄 PDF report")))
+            .on_hover_text(tr("Save a printable one-page summary of this analysis."))
+            .on_disabled_hover_text(tr(
+                "Correct all three inputs, including the graphed one, to save a report.",
+            ));
+        if button.clicked() {
+            let bytes = self.report(settings).to_bytes();
+            self.report_error = files::save("top-gun-report.pdf", &bytes, FileType::PDF).err();
+        }
+    }
+
+    /// Whether every input the report prints is valid. Unlike `has_errors`,
+    /// this includes the graphed variable, whose value is hidden on the page.
+    fn can_report(&self) -> bool {
+        [
+            (PROJECTILE_WEIGHT, self.projectile_weight),
+            (MUZZLE_VELOCITY, self.muzzle_velocity),
+            (RIFLE_WEIGHT, self.rifle_weight),
+        ]
+        .iter()
+        // Errors do not depend on the display units
+        .all(|(input, value)| !input.validate(*value, UnitSystem::Imperial).is_error())
+    }
+
+    /// One-page PDF with the inputs, results, plot and disclaimer.
+    fn report(&self, settings: &Settings) -> Document {
+        const MARGIN: f32 = 50.0;
+        const VALUE_COLUMN: f32 = MARGIN + 190.0;
+
+        let units = settings.units;
+        // Printed on white paper
+        let colors = settings.palette.plot_colors(false);
+        let mut page = Page::new(pdf::A4);
+        let width = page.width() - 2.0 * MARGIN;
+
+        let mut y = MARGIN + 18.0;
+        let title = tr("TOP Gun Calculator");
+        page.text(MARGIN, y, 18.0, Font::Bold, Align::Left, title);
+        y += 32.0;
+
+        let ke = Self::calculate_kinetic_energy(self.projectile_weight, self.muzzle_velocity);
+        let moa = Self::calculate_moa(ke, self.rifle_weight);
+        let band = |low: f64, high: f64| {
+            format!(
+                "{}–{} MOA",
+                format_number(moa * low, 2),
+                format_number(moa * high, 2)
+            )
+        };
+        let (value_for_1moa, quantity) = self.calculate_value_for_1moa();
+        let graphed = self.graph_variable.input();
+        let sections = [
+            (
+                tr("Inputs"),
+                vec![
+                    (
+                        format!("{}:", tr(PROJECTILE_WEIGHT.name)),
+                        PROJECTILE_WEIGHT
+                            .quantity
+                            .format(units, self.projectile_weight),
+                    ),
+                    (
+                        format!("{}:", tr(MUZZLE_VELOCITY.name)),
+                        MUZZLE_VELOCITY.quantity.format(units, self.muzzle_velocity),
+                    ),
+                    (
+                        format!("{}:", tr(RIFLE_WEIGHT.name)),
+                        RIFLE_WEIGHT.quantity.format(units, self.rifle_weight),
+                    ),
+                    (
+                        tr("Graph:").to_string(),
+                        self.graph_variable.label().to_string(),
+                    ),
+                ],
+            ),
+            (
+                tr("Results"),
+                vec![
+                    (
+                        tr("Kinetic energy:").to_string(),
+                        Quantity::Energy.format(units, ke),
+                    ),
+                    (
+                        tr("Expected 5-round group:").to_string(),
+                        format!("{} MOA", format_number(moa, 2)),
+                    ),
+                    (format!("{}:", tr("1σ (68%)")), band(0.85, 1.15)),
+                    (format!("{}:", tr("2σ (95%)")), band(0.70, 1.30)),
+                    (
+                        tr_args("{0} for 1 MOA:", &[tr(graphed.name)]),
+                        quantity.format(units, value_for_1moa),
+                    ),
+                ],
+            ),
+        ];
+        for (heading, rows) in sections {
+            page.text(MARGIN, y, 12.0, Font::Bold, Align::Left, heading);
+            y += 17.0;
+            for (label, value) in rows {
+                page.text(MARGIN, y, 10.0, Font::Regular, Align::Left, &label);
+                page.text(VALUE_COLUMN, y, 10.0, Font::Bold, Align::Left, &value);
+                y += 14.0;
+            }
+            y += 10.0;
+        }
+
+        let (expected, sd1_upper, sd1_lower, sd2_upper, sd2_lower, x_label, y_label) =
+            self.generate_plot_data(units);
+        let current = [
+            graphed
+                .quantity
+                .display_value(units, self.current_value(self.graph_variable)),
+            moa,
+        ];
+        let band_series = |label, points, color| Series {
+            label,
+            points,
+            color,
+            width: 1.0,
+            dashed: true,
+        };
+        let chart = Chart {
+            x_label: &x_label,
+            y_label: &y_label,
+            series: vec![
+                band_series(Some(tr("2σ (95%)")), &sd2_upper, colors.sigma2),
+                band_series(None, &sd2_lower, colors.sigma2),
+                band_series(Some(tr("1σ (68%)")), &sd1_upper, colors.sigma1),
+                band_series(None, &sd1_lower, colors.sigma1),
+                Series {
+                    label: Some(tr("Expected Precision")),
+                    points: &expected,
+                    color: colors.expected,
+                    width: 1.8,
+                    dashed: false,
+                },
+            ],
+            markers: vec![(current, colors.expected)],
+        };
+        page.chart(MARGIN, y, width, 300.0, &chart);
+        y += 320.0;
+
+        for paragraph in DISCLAIMER {
+            y = page.paragraph(MARGIN, y, width, 8.5, Font::Regular, tr(paragraph));
+            y += 3.0;
+        }
+        for (i, (label, url)) in [
+            (tr("Community:"), COMMUNITY_URL),
+            (tr("Original:"), SPREADSHEET_URL),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let y = y + 6.0 + 11.0 * i as f32;
+            page.text(MARGIN, y, 8.5, Font::Bold, Align::Left, label);
+            page.text(MARGIN + 60.0, y, 8.5, Font::Regular, Align::Left, url);
+        }
+
+        let mut document = Document::new(title);
+        document.add_page(page);
+        document
+    }
+
+    pub fn ui(&mut self, ctx: &egui::Context, settings: &Settings) {
+        let units = settings.units;
+        self.handle_shortcuts(ctx);
+
+        egui::TopBottomPanel::top("config_panel").show(ctx, |ui| {
+            egui::Frame::default()
+                .fill(ui.style().visuals.faint_bg_color)
+                .inner_margin(egui::vec2(10.0, 10.0))
+                .show(ui, |ui| {
+                    self.render_config_bar(ui, settings);
+                    self.render_validation(ui, units);
+                    if let Some(error) = &self.report_error {
+                        ui.colored_label(ui.visuals().error_fg_color, format!("⛔ {}", error));
+                    }
+                });
+        });
+
+        egui::TopBottomPanel::bottom("footer_panel").show(ctx, |ui| {
+            self.render_footer(ui);
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            if self.has_errors() {
+                ui.centered_and_justified(|ui| {
+                    ui.label(tr("Correct the inputs above to see the plot."));
+                });
+            } else {
+                // Full-width plot in remaining space
+                self.render_plot(ui, settings);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kinetic_energy_calculation() {
+        // Test with 168 grain bullet at 2650 fps
+        let ke = TopApp::calculate_kinetic_energy(168.0, 2650.0);
+        // Expected: (168 * 2650^2) / 450436 = 2619.96
+        assert!((ke - 2619.96).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_kinetic_energy_known_values() {
+        // 150 grain at 3000 fps
+        let ke = TopApp::calculate_kinetic_energy(150.0, 3000.0);
+        assert!((ke - 2997.10).abs() < 1.0);
+
+        // 55 grain at 3240 fps (common .223)
+        let ke = TopApp::calculate_kinetic_energy(55.0, 3240.0);
+        assert!((ke - 1281.75).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_moa_calculation() {
+        // 2620 ft-lbs with 12 lb rifle
+        let moa = TopApp::calculate_moa(2620.0, 12.0);
+        // Expected: 2620 / 200 / 12 = 1.092
+        assert!((moa - 1.092).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_moa_heavier_rifle() {
+        // Heavier rifle should have better precision (lower MOA)
+        let ke = 2620.0;
+        let moa_light = TopApp::calculate_moa(ke, 8.0);
+        let moa_heavy = TopApp::calculate_moa(ke, 16.0);
+        assert!(moa_heavy < moa_light);
+    }
+
+    #[test]
+    fn test_full_calculation_chain() {
+        // .308 Win: 168gr @ 2650fps, 12lb rifle
+        let ke = TopApp::calculate_kinetic_energy(168.0, 2650.0);
+        let moa = TopApp::calculate_moa(ke, 12.0);
+        // Should be around 1.09 MOA
+        assert!(moa > 1.0 && moa < 1.2);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        // Minimum values
+        let ke_min = TopApp::calculate_kinetic_energy(50.0, 500.0);
+        assert!(ke_min > 0.0);
+
+        let moa_min = TopApp::calculate_moa(ke_min, 5.0);
+        assert!(moa_min > 0.0);
+
+        // Maximum values
+        let ke_max = TopApp::calculate_kinetic_energy(500.0, 5000.0);
+        assert!(ke_max > 0.0);
+
+        let moa_max = TopApp::calculate_moa(ke_max, 50.0);
+        assert!(moa_max > 0.0);
+    }
+
+    #[test]
+    fn test_graph_variable_selection() {
+        let mut app = TopApp {
+            projectile_weight: 168.0,
+            muzzle_velocity: 2650.0,
+            rifle_weight: 12.0,
+            graph_variable: GraphVariable::RifleWeight,
+            hover_point: None,
+            report_error: None,
+        };
+
+        // Test RifleWeight selection
+        assert_eq!(app.graph_variable, GraphVariable::RifleWeight);
+        let (_value, quantity) = app.calculate_value_for_1moa();
+        assert_eq!(quantity.unit(UnitSystem::Imperial), "lbs");
+
+        // Test Velocity selection
+        app.graph_variable = GraphVariable::Velocity;
+        let (_value, quantity) = app.calculate_value_for_1moa();
+        assert_eq!(quantity.unit(UnitSystem::Imperial), "fps");
+
+        // Test ProjectileWeight selection
+        app.graph_variable = GraphVariable::ProjectileWeight;
+        let (_value, quantity) = app.calculate_value_for_1moa();
+        assert_eq!(quantity.unit(UnitSystem::Imperial), "gr");
+    }
+
+    #[test]
+    fn test_graph_variable_labels() {
+        assert_eq!(GraphVariable::RifleWeight.label(), "Rifle Weight");
+        assert_eq!(GraphVariable::Velocity.label(), "Velocity");
+        assert_eq!(GraphVariable::ProjectileWeight.label(), "Projectile Weight");
+    }
+
+    #[test]
+    fn test_input_changes() {
+        let mut app = TopApp::default();
+        let before = app.inputs();
+        app.rifle_weight = 14.0;
+        app.graph_variable = GraphVariable::Velocity;
+
+        assert_eq!(
+            app.inputs().changes(&before, UnitSystem::Imperial),
+            vec!["Rifle weight: 14.00 lbs", "Graph: Velocity"]
+        );
+        assert!(before.changes(&before, UnitSystem::Imperial).is_empty());
+
+        app.set_inputs(before);
+        assert_eq!(app.inputs(), before);
+    }
+
+    #[test]
+    fn test_report_contents() {
+        let app = TopApp::default();
+        let bytes = app.report(&Settings::default()).to_bytes();
+        let text = String::from_utf8_lossy(&bytes);
+
+        assert!(text.starts_with("%PDF-"));
+        assert!(text.contains("(TOP Gun Calculator) Tj"));
+        assert!(text.contains("(2619 ft"));
+        assert!(text.contains("(13.10 lbs) Tj"));
+        assert!(text.contains("(Results from this tool are an estimate only,"));
+    }
+
+    #[test]
+    fn test_report_needs_the_graphed_input() {
+        let mut app = TopApp {
+            rifle_weight: 0.0,
+            ..TopApp::default()
+        };
+        // The hidden rifle weight would make the report's MOA infinite
+        assert!(!app.has_errors());
+        assert!(!app.can_report());
+
+        app.rifle_weight = 12.0;
+        assert!(app.can_report());
+        app.muzzle_velocity = -1.0;
+        assert!(!app.can_report());
+    }
+
+    #[test]
+    fn test_plot_summary() {
+        let app = TopApp::default();
+        assert_eq!(
+            app.plot_summary(UnitSystem::Imperial),
+            "5-Round Group Size (MOA) versus Rifle Weight (lbs). \
+             At Rifle weight 12.00 lbs: expected 1.09 MOA, 1σ band 0.93–1.26 MOA (0.33 MOA wide), \
+             2σ band 0.76–1.42 MOA (0.65 MOA wide). 1 MOA @ 13.10 lbs."
+        );
+    }
+
+    #[test]
+    fn test_plot_summary_skips_invalid_current_value() {
+        let app = TopApp {
+            rifle_weight: 0.0,
+            ..TopApp::default()
+        };
+        assert_eq!(
+            app.plot_summary(UnitSystem::Imperial),
+            "5-Round Group Size (MOA) versus Rifle Weight (lbs). 1 MOA @ 13.10 lbs."
+        );
+    }
+
+    #[test]
+    fn test_validation_uses_visible_inputs() {
+        let mut app = TopApp {
+            projectile_weight: 168.0,
+            muzzle_velocity: 2650.0,
+            rifle_weight: 0.0,
+            graph_variable: GraphVariable::RifleWeight,
+            hover_point: None,
+            report_error: None,
+        };
+
+        // Rifle weight is on the x axis, so the invalid value is not used
+        assert!(!app.has_errors());
+
+        app.graph_variable = GraphVariable::Velocity;
+        assert!(app.has_errors());
+        assert_eq!(app.value_for_1moa_text(UnitSystem::Imperial), "1 MOA @ —");
+
+        app.rifle_weight = 60.0;
+        assert!(!app.has_errors());
+        assert!(matches!(
+            app.validation_messages(UnitSystem::Imperial).as_slice(),
+            [Validation::Warning(_)]
+        ));
+    }
+}
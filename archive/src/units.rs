@@ -0,0 +1,237 @@
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{format_number, tr};
+
+/// Unit system shared by every calculator. Calculations are done in imperial
+/// units; values are converted only for display and entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum UnitSystem {
+    #[default]
+    Imperial,
+    Metric,
+}
+
+impl UnitSystem {
+    pub fn label(&self) -> &'static str {
+        match self {
+            UnitSystem::Imperial => tr("Imperial"),
+            UnitSystem::Metric => tr("Metric"),
+        }
+    }
+}
+
+const GRAMS_PER_GRAIN: f64 = 0.064_798_91;
+const METERS_PER_FOOT: f64 = 0.3048;
+const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+const JOULES_PER_FOOT_POUND: f64 = 1.355_817_948;
+const MILLIMETERS_PER_INCH: f64 = 25.4;
+const CENTIMETERS_PER_INCH: f64 = 2.54;
+const METERS_PER_YARD: f64 = 0.9144;
+const HECTOPASCALS_PER_INCH_OF_MERCURY: f64 = 33.8639;
+const METERS_PER_SECOND_PER_MPH: f64 = 0.447_04;
+
+/// A physical quantity that can be shown in either unit system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantity {
+    /// Projectile weight, stored in grains.
+    BulletWeight,
+    /// Velocity, stored in feet per second.
+    Velocity,
+    /// Rifle weight, stored in pounds.
+    RifleWeight,
+    /// Kinetic energy, stored in foot-pounds.
+    Energy,
+    /// Bullet diameter, stored in inches.
+    Diameter,
+    /// Bullet length, stored in inches.
+    BulletLength,
+    /// Barrel twist, stored in inches per turn.
+    Twist,
+    /// Height of the sight above the bore, stored in inches.
+    SightHeight,
+    /// Distance downrange, stored in yards.
+    Range,
+    /// Drop or drift at the target, stored in inches.
+    Deflection,
+    /// Air temperature, stored in degrees Fahrenheit.
+    Temperature,
+    /// Air pressure, stored in inches of mercury.
+    Pressure,
+    /// Altitude, stored in feet.
+    Altitude,
+    /// Wind speed, stored in miles per hour.
+    WindSpeed,
+}
+
+impl Quantity {
+    pub fn unit(&self, system: UnitSystem) -> &'static str {
+        match (self, system) {
+            (Quantity::BulletWeight, UnitSystem::Imperial) => "gr",
+            (Quantity::BulletWeight, UnitSystem::Metric) => "g",
+            (Quantity::Velocity, UnitSystem::Imperial) => "fps",
+            (Quantity::Velocity, UnitSystem::Metric) => "m/s",
+            (Quantity::RifleWeight, UnitSystem::Imperial) => "lbs",
+            (Quantity::RifleWeight, UnitSystem::Metric) => "kg",
+            (Quantity::Energy, UnitSystem::Imperial) => "ft·lbf",
+            (Quantity::Energy, UnitSystem::Metric) => "J",
+            (Quantity::Diameter, UnitSystem::Imperial) => "in",
+            (Quantity::Diameter, UnitSystem::Metric) => "mm",
+            (Quantity::BulletLength, UnitSystem::Imperial) => "in",
+            (Quantity::BulletLength, UnitSystem::Metric) => "mm",
+            (Quantity::Twist, UnitSystem::Imperial) => "in",
+            (Quantity::Twist, UnitSystem::Metric) => "mm",
+            (Quantity::SightHeight, UnitSystem::Imperial) => "in",
+            (Quantity::SightHeight, UnitSystem::Metric) => "cm",
+            (Quantity::Range, UnitSystem::Imperial) => "yd",
+            (Quantity::Range, UnitSystem::Metric) => "m",
+            (Quantity::Deflection, UnitSystem::Imperial) => "in",
+            (Quantity::Deflection, UnitSystem::Metric) => "cm",
+            (Quantity::Temperature, UnitSystem::Imperial) => "°F",
+            (Quantity::Temperature, UnitSystem::Metric) => "°C",
+            (Quantity::Pressure, UnitSystem::Imperial) => "inHg",
+            (Quantity::Pressure, UnitSystem::Metric) => "hPa",
+            (Quantity::Altitude, UnitSystem::Imperial) => "ft",
+            (Quantity::Altitude, UnitSystem::Metric) => "m",
+            (Quantity::WindSpeed, UnitSystem::Imperial) => "mph",
+            (Quantity::WindSpeed, UnitSystem::Metric) => "m/s",
+        }
+    }
+
+    /// Decimal places that are meaningful for the displayed unit.
+    pub fn decimals(&self, system: UnitSystem) -> usize {
+        match (self, system) {
+            (Quantity::BulletWeight, UnitSystem::Imperial) => 1,
+            (Quantity::BulletWeight, UnitSystem::Metric) => 2,
+            (Quantity::Velocity, _) => 0,
+            (Quantity::RifleWeight, _) => 2,
+            (Quantity::Energy, _) => 0,
+            (Quantity::Diameter, UnitSystem::Imperial) => 3,
+            (Quantity::Diameter, UnitSystem::Metric) => 2,
+            (Quantity::BulletLength, UnitSystem::Imperial) => 3,
+            (Quantity::BulletLength, UnitSystem::Metric) => 2,
+            (Quantity::Twist, UnitSystem::Imperial) => 1,
+            (Quantity::Twist, UnitSystem::Metric) => 0,
+            (Quantity::SightHeight, _) => 1,
+            (Quantity::Range, _) => 0,
+            (Quantity::Deflection, _) => 1,
+            (Quantity::Temperature, _) => 0,
+            (Quantity::Pressure, UnitSystem::Imperial) => 2,
+            (Quantity::Pressure, UnitSystem::Metric) => 0,
+            (Quantity::Altitude, _) => 0,
+            (Quantity::WindSpeed, _) => 1,
+        }
+    }
+
+    fn factor(&self, system: UnitSystem) -> f64 {
+        match (self, system) {
+            (_, UnitSystem::Imperial) => 1.0,
+            (Quantity::BulletWeight, UnitSystem::Metric) => GRAMS_PER_GRAIN,
+            (Quantity::Velocity, UnitSystem::Metric) => METERS_PER_FOOT,
+            (Quantity::RifleWeight, UnitSystem::Metric) => KILOGRAMS_PER_POUND,
+            (Quantity::Energy, UnitSystem::Metric) => JOULES_PER_FOOT_POUND,
+            (Quantity::Diameter, UnitSystem::Metric) => MILLIMETERS_PER_INCH,
+            (Quantity::BulletLength, UnitSystem::Metric) => MILLIMETERS_PER_INCH,
+            (Quantity::Twist, UnitSystem::Metric) => MILLIMETERS_PER_INCH,
+            (Quantity::SightHeight, UnitSystem::Metric) => CENTIMETERS_PER_INCH,
+            (Quantity::Range, UnitSystem::Metric) => METERS_PER_YARD,
+            (Quantity::Deflection, UnitSystem::Metric) => CENTIMETERS_PER_INCH,
+            (Quantity::Temperature, UnitSystem::Metric) => 5.0 / 9.0,
+            (Quantity::Pressure, UnitSystem::Metric) => HECTOPASCALS_PER_INCH_OF_MERCURY,
+            (Quantity::Altitude, UnitSystem::Metric) => METERS_PER_FOOT,
+            (Quantity::WindSpeed, UnitSystem::Metric) => METERS_PER_SECOND_PER_MPH,
+        }
+    }
+
+    /// Stored value that is zero in the display unit; only temperature
+    /// scales don't share a zero.
+    fn offset(&self, system: UnitSystem) -> f64 {
+        match (self, system) {
+            (Quantity::Temperature, UnitSystem::Metric) => 32.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Converts a stored value into the display unit.
+    pub fn display_value(&self, system: UnitSystem, value: f64) -> f64 {
+        (value - self.offset(system)) * self.factor(system)
+    }
+
+    /// Converts a value in the display unit back to the stored unit.
+    pub fn stored_value(&self, system: UnitSystem, value: f64) -> f64 {
+        value / self.factor(system) + self.offset(system)
+    }
+
+    /// Formats a stored value in the display unit, including the unit suffix.
+    pub fn format(&self, system: UnitSystem, value: f64) -> String {
+        format!(
+            "{} {}",
+            format_number(self.display_value(system, value), self.decimals(system)),
+            self.unit(system)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metric_conversions() {
+        let grams = Quantity::BulletWeight.display_value(UnitSystem::Metric, 168.0);
+        assert!((grams - 10.886).abs() < 0.001);
+
+        let mps = Quantity::Velocity.display_value(UnitSystem::Metric, 2650.0);
+        assert!((mps - 807.72).abs() < 0.01);
+
+        let kg = Quantity::RifleWeight.display_value(UnitSystem::Metric, 12.0);
+        assert!((kg - 5.443).abs() < 0.001);
+
+        let joules = Quantity::Energy.display_value(UnitSystem::Metric, 2620.0);
+        assert!((joules - 3552.2).abs() < 0.1);
+
+        let meters = Quantity::Range.display_value(UnitSystem::Metric, 1000.0);
+        assert!((meters - 914.4).abs() < 1e-9);
+
+        let celsius = Quantity::Temperature.display_value(UnitSystem::Metric, 59.0);
+        assert!((celsius - 15.0).abs() < 1e-9);
+
+        let hectopascals = Quantity::Pressure.display_value(UnitSystem::Metric, 29.92);
+        assert!((hectopascals - 1013.2).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for quantity in [
+            Quantity::BulletWeight,
+            Quantity::Velocity,
+            Quantity::RifleWeight,
+            Quantity::Energy,
+            Quantity::Diameter,
+            Quantity::BulletLength,
+            Quantity::Twist,
+            Quantity::SightHeight,
+            Quantity::Range,
+            Quantity::Deflection,
+            Quantity::Temperature,
+            Quantity::Pressure,
+            Quantity::Altitude,
+            Quantity::WindSpeed,
+        ] {
+            let shown = quantity.display_value(UnitSystem::Metric, 123.4);
+            let stored = quantity.stored_value(UnitSystem::Metric, shown);
+            assert!((stored - 123.4).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            Quantity::Velocity.format(UnitSystem::Imperial, 2650.0),
+            "2650 fps"
+        );
+        assert_eq!(
+            Quantity::RifleWeight.format(UnitSystem::Metric, 12.0),
+            "5.44 kg"
+        );
+    }
+}